@@ -1,10 +1,8 @@
-use heterob::U16;
 #[cfg(test)]
-
 use heterob::{
-    bit_numbering::Lsb,
-    endianness::{Be, BeBytesTryInto, Le, LeBytesTryInto, TryFromLeBytes},
-    Bool, Seq, P1, P2, P4, U8,
+    bit_numbering::{FromBits, Lsb},
+    endianness::{Be, BeBytesTryInto, FromLeBytesSlice, Le, LeBytesTryInto, TryFromLeBytes},
+    Bool, Seq, P1, P2, P3, P4, U16, U8,
 };
 
 #[test]
@@ -33,11 +31,13 @@ fn array_from_slice_with_offset() {
 #[test]
 fn integer_from_slice() {
     let data = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55].as_slice();
-    // We can't use TryInto trait directly like this
-    // let Seq {
-    //     head: Le(result), ..
-    // }: Seq<Le<u32>, _> = data.try_into().unwrap();
-    // But we can use LeBytesTryInto trait:
+    // We can use TryInto trait directly with endianness wrapper
+    let Seq {
+        head: Le(result), ..
+    }: Seq<Le<u32>, _> = data.try_into().unwrap();
+    assert_eq!(0x33221100, result);
+
+    // Or we can use LeBytesTryInto trait:
     let Seq { head: result, tail }: Seq<u32, _> = data.le_bytes_try_into().unwrap();
     assert_eq!(0x33221100, result);
 
@@ -45,6 +45,18 @@ fn integer_from_slice() {
     assert_eq!(0x4455u16, result);
 }
 
+#[test]
+fn integers_tuple_from_slice() {
+    let data = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55].as_slice();
+    // Every tuple element defines its own endianness
+    let Seq {
+        head: (Le(byte), Be(word), Le(dword)),
+        tail,
+    }: Seq<(_, _, Le<u16>), _> = data.try_into().unwrap();
+    assert_eq!((0x00u8, 0x1122u16, 0x4433), (byte, word, dword));
+    assert_eq!(&[0x55], tail);
+}
+
 #[test]
 fn single_integer_as_partable_from_slice() {
     let data = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55].as_slice();
@@ -73,6 +85,10 @@ fn integers_array_from_slice() {
     let Seq { head, .. } = data.le_bytes_try_into().unwrap();
     let Le(result): Le<[u16; 2]> = From::<[u8; 4]>::from(head);
     assert_eq!([0x1100, 0x3322], result);
+
+    // Or we can use conversion with array length known from type
+    let Seq { head: result, .. } = <[u16; 2]>::from_le_bytes_slice(data).unwrap();
+    assert_eq!([0x1100, 0x3322], result);
 }
 
 #[test]
@@ -121,6 +137,32 @@ fn bitfields_with_implemented_from_uint() {
     );
 }
 
+#[test]
+fn bitfields_with_implemented_from_bits() {
+    // enum has FromBits implementation, so it can be used without wrapper
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    enum Num {
+        One,
+        Two,
+        Three,
+        Four,
+    }
+    impl FromBits<2> for Num {
+        fn from_bits(bits: u128) -> Self {
+            match bits {
+                0b00 => Self::One,
+                0b01 => Self::Two,
+                0b10 => Self::Three,
+                0b11 => Self::Four,
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    let Lsb((just_bool, num, just_u8)) = P3::<u8, 1, 2, 5>(0b0101_0101).into();
+    assert_eq!((true, Num::Three, 0b01010u8), (just_bool, num, just_u8));
+}
+
 #[test]
 fn types_with_implemented_from_uint() {
     let Le((
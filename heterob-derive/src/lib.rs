@@ -0,0 +1,416 @@
+/*!
+Derive macros for [heterob](https://docs.rs/heterob) traits
+
+Macros are re-exported by `heterob` crate behind `derive` feature, so there is no need to
+depend on this crate directly.
+
+Generated implementations reuse `heterob` tuple wrappers, so compile time length checks
+still fire on mismatched field widths.
+
+## Bytes
+
+[FromLeBytes] and [FromBeBytes] derives split bytes array into fields in declaration order.
+They also implement `FromLeBytesSlice` / `FromBeBytesSlice`, so structs could be decoded from
+slices and as array elements.
+Field width is a wire size of field type (`FromLeBytesSlice::WIRE_SIZE` / `FromBeBytesSlice::WIRE_SIZE`
+of [Le](https://docs.rs/heterob/latest/heterob/endianness/struct.Le.html) /
+[Be](https://docs.rs/heterob/latest/heterob/endianness/struct.Be.html) wrapped field type),
+so nested derived structs take exactly their fields width regardless of padding.
+It could be overridden with `#[bytes(N)]` attribute.
+Endianness of a single field could be overridden with `#[le]` or `#[be]` attributes.
+
+```rust
+use heterob::endianness::LeBytesInto;
+use heterob_derive::FromLeBytes;
+
+#[derive(Debug, PartialEq, Eq, FromLeBytes)]
+struct S {
+    byte: u8,
+    word: u16,
+    #[be]
+    dword: u32,
+    #[bytes(3)]
+    bytes: [u8; 3],
+}
+
+let s: S = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99].le_bytes_into();
+let sample = S {
+    byte: 0x00,
+    word: 0x2211,
+    dword: 0x33445566,
+    bytes: [0x77, 0x88, 0x99],
+};
+assert_eq!(sample, s);
+```
+
+## Bits
+
+[FromLsb] and [FromMsb] derives partition an integer or an array into fields in declaration
+order. Source type is set with struct level `#[bits(TYPE)]` attribute. Field width is set with
+`#[bits(N)]` attribute, otherwise it is `1` for `bool` and `BITS` of primitive integers.
+Fields of other types require `#[bits(N)]` attribute. Structs with more than 26 fields are
+partitioned with `HCons` lists.
+
+```rust
+use heterob::bit_numbering::LsbInto;
+use heterob_derive::FromLsb;
+
+#[derive(Debug, PartialEq, Eq, FromLsb)]
+#[bits(u16)]
+struct S {
+    is_enabled: bool,
+    #[bits(3)]
+    kind: u8,
+    #[bits(4)]
+    _reserved: (),
+    byte: u8,
+}
+
+let s: S = 0b1010_1010_0000_1011u16.lsb_into();
+let sample = S {
+    is_enabled: true,
+    kind: 0b101,
+    _reserved: (),
+    byte: 0b1010_1010,
+};
+assert_eq!(sample, s);
+```
+
+Fields without width attribute fail to compile unless they are `bool` or primitive integers
+```compile_fail
+use heterob::bit_numbering::LsbInto;
+use heterob_derive::FromLsb;
+
+#[derive(FromLsb)]
+#[bits(u8)]
+struct S {
+    kind: heterob::U8<u8>,
+}
+```
+
+Field widths that do not fit into source integer fail to compile
+```compile_fail
+use heterob::bit_numbering::LsbInto;
+use heterob_derive::FromLsb;
+
+#[derive(FromLsb)]
+#[bits(u8)]
+struct S {
+    #[bits(4)]
+    a: u8,
+    #[bits(5)]
+    b: u8,
+}
+
+let _: S = 0u8.lsb_into();
+```
+*/
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Expr, Fields, Type};
+
+/// Max arity of `heterob` partition wrappers, longer partitions are `HCons` lists
+const MAX_ARITY: usize = 26;
+
+/// Primitive integer types with width inferred from `BITS` constant
+const INTEGERS: [&str; 12] = [
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
+
+#[derive(Clone, Copy)]
+enum Endianness {
+    Le,
+    Be,
+}
+
+#[derive(Clone, Copy)]
+enum BitNumbering {
+    Lsb,
+    Msb,
+}
+
+/// Derives `heterob::endianness::FromLeBytes` for structs
+///
+/// Fields are little endian by default.
+#[proc_macro_derive(FromLeBytes, attributes(le, be, bytes))]
+pub fn derive_from_le_bytes(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_bytes(&input, Endianness::Le)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derives `heterob::endianness::FromBeBytes` for structs
+///
+/// Fields are big endian by default.
+#[proc_macro_derive(FromBeBytes, attributes(le, be, bytes))]
+pub fn derive_from_be_bytes(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_bytes(&input, Endianness::Be)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derives `heterob::bit_numbering::FromLsb` for structs
+#[proc_macro_derive(FromLsb, attributes(bits))]
+pub fn derive_from_lsb(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_bits(&input, BitNumbering::Lsb)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derives `heterob::bit_numbering::FromMsb` for structs
+#[proc_macro_derive(FromMsb, attributes(bits))]
+pub fn derive_from_msb(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_bits(&input, BitNumbering::Msb)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Struct fields with constructor of `Self` from field bindings
+struct StructFields<'a> {
+    fields: Vec<&'a syn::Field>,
+    bindings: Vec<syn::Ident>,
+    constructor: TokenStream2,
+}
+
+fn struct_fields(input: &DeriveInput) -> syn::Result<StructFields<'_>> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(
+            input.generics.span(),
+            "generic structs are not supported",
+        ));
+    }
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(input.span(), "only structs are supported"));
+    };
+    let fields: Vec<_> = data.fields.iter().collect();
+    let bindings: Vec<_> = (0..fields.len()).map(|n| format_ident!("f{}", n)).collect();
+    let constructor = match &data.fields {
+        Fields::Named(_) => {
+            let names = fields.iter().map(|f| &f.ident);
+            quote!(Self { #(#names: #bindings,)* })
+        }
+        Fields::Unnamed(_) => quote!(Self(#(#bindings,)*)),
+        Fields::Unit => quote!(Self),
+    };
+    Ok(StructFields {
+        fields,
+        bindings,
+        constructor,
+    })
+}
+
+/// Width from `#[NAME(expr)]` attribute
+fn width_attr(field: &syn::Field, name: &str) -> syn::Result<Option<Expr>> {
+    field
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident(name))
+        .map(|attr| attr.parse_args())
+        .transpose()
+}
+
+fn endianness_attr(field: &syn::Field, default: Endianness) -> syn::Result<Endianness> {
+    let le = field.attrs.iter().find(|attr| attr.path().is_ident("le"));
+    let be = field.attrs.iter().find(|attr| attr.path().is_ident("be"));
+    match (le, be) {
+        (Some(_), Some(attr)) => Err(Error::new(
+            attr.span(),
+            "`#[le]` and `#[be]` are mutually exclusive",
+        )),
+        (Some(_), None) => Ok(Endianness::Le),
+        (None, Some(_)) => Ok(Endianness::Be),
+        (None, None) => Ok(default),
+    }
+}
+
+fn from_bytes(input: &DeriveInput, endianness: Endianness) -> syn::Result<TokenStream2> {
+    let StructFields {
+        fields,
+        bindings,
+        constructor,
+    } = struct_fields(input)?;
+
+    let mut widths = Vec::with_capacity(fields.len());
+    let mut values = Vec::with_capacity(fields.len());
+    for (field, binding) in fields.iter().zip(&bindings) {
+        let ty = &field.ty;
+        let field_endianness = endianness_attr(field, endianness)?;
+        let width = match (width_attr(field, "bytes")?, field_endianness) {
+            (Some(expr), _) => quote!(#expr),
+            (None, Endianness::Le) => quote! {
+                <::heterob::endianness::Le<#ty> as ::heterob::endianness::FromLeBytesSlice>::WIRE_SIZE
+            },
+            (None, Endianness::Be) => quote! {
+                <::heterob::endianness::Be<#ty> as ::heterob::endianness::FromBeBytesSlice>::WIRE_SIZE
+            },
+        };
+        values.push(match field_endianness {
+            Endianness::Le => quote! {
+                <#ty as ::heterob::endianness::FromLeBytes<{ #width }>>::from_le_bytes(#binding)
+            },
+            Endianness::Be => quote! {
+                <#ty as ::heterob::endianness::FromBeBytes<{ #width }>>::from_be_bytes(#binding)
+            },
+        });
+        widths.push(width);
+    }
+
+    // Bytes are split field by field with T2 wrapper, so every step is checked at compile time
+    let mut splits = Vec::with_capacity(fields.len());
+    for (n, binding) in bindings.iter().enumerate() {
+        let width = &widths[n];
+        if n + 1 == bindings.len() {
+            splits.push(quote!(let #binding: [u8; #width] = _bytes;));
+        } else {
+            let rest = &widths[n + 1..];
+            splits.push(quote! {
+                let ::heterob::T2(#binding, _bytes): ::heterob::T2<[u8; #width], [u8; 0 #(+ #rest)*]> =
+                    _bytes.into();
+            });
+        }
+    }
+
+    let name = &input.ident;
+    let (trait_name, fn_name, slice_trait_name, slice_fn_name, try_trait_name, try_fn_name) =
+        match endianness {
+            Endianness::Le => (
+                quote!(FromLeBytes),
+                quote!(from_le_bytes),
+                quote!(FromLeBytesSlice),
+                quote!(from_le_bytes_slice),
+                quote!(TryFromLeBytes),
+                quote!(try_from_le_bytes),
+            ),
+            Endianness::Be => (
+                quote!(FromBeBytes),
+                quote!(from_be_bytes),
+                quote!(FromBeBytesSlice),
+                quote!(from_be_bytes_slice),
+                quote!(TryFromBeBytes),
+                quote!(try_from_be_bytes),
+            ),
+        };
+    Ok(quote! {
+        impl ::heterob::endianness::#trait_name<{ 0 #(+ #widths)* }> for #name {
+            #[allow(clippy::let_unit_value)]
+            fn #fn_name(bytes: [u8; 0 #(+ #widths)*]) -> Self {
+                let _bytes = bytes;
+                #(#splits)*
+                #(let #bindings = #values;)*
+                #constructor
+            }
+        }
+
+        impl ::heterob::endianness::#slice_trait_name for #name {
+            const WIRE_SIZE: usize = 0 #(+ #widths)*;
+            fn #slice_fn_name(
+                slice: &[u8],
+            ) -> ::core::result::Result<
+                ::heterob::Seq<Self, &[u8]>,
+                ::core::array::TryFromSliceError,
+            > {
+                <Self as ::heterob::endianness::#try_trait_name<{ 0 #(+ #widths)* }>>::#try_fn_name(slice)
+            }
+        }
+    })
+}
+
+fn from_bits(input: &DeriveInput, bit_numbering: BitNumbering) -> syn::Result<TokenStream2> {
+    let source: Type = input
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("bits"))
+        .ok_or_else(|| {
+            Error::new(
+                input.span(),
+                "source type should be set with `#[bits(TYPE)]` struct attribute",
+            )
+        })?
+        .parse_args()?;
+    let StructFields {
+        fields,
+        bindings,
+        constructor,
+    } = struct_fields(input)?;
+    if fields.is_empty() {
+        return Err(Error::new(
+            input.span(),
+            "bit partition should have at least 1 field",
+        ));
+    }
+
+    let mut widths = Vec::with_capacity(fields.len());
+    for field in &fields {
+        let ty = &field.ty;
+        let primitive = match ty {
+            Type::Path(p) if p.qself.is_none() => p.path.get_ident(),
+            _ => None,
+        };
+        widths.push(match (width_attr(field, "bits")?, primitive) {
+            (Some(expr), _) => quote!(#expr),
+            (None, Some(ident)) if ident == "bool" => quote!(1),
+            (None, Some(ident)) if INTEGERS.iter().any(|int| ident == int) => {
+                quote!(<#ty>::BITS as usize)
+            }
+            (None, _) => {
+                return Err(Error::new(
+                    ty.span(),
+                    "field width should be set with `#[bits(N)]` attribute",
+                ))
+            }
+        });
+    }
+
+    let name = &input.ident;
+    let (trait_name, fn_name, into_trait, into_fn) = match bit_numbering {
+        BitNumbering::Lsb => (
+            quote!(FromLsb),
+            quote!(from_lsb),
+            quote!(LsbInto),
+            quote!(lsb_into),
+        ),
+        BitNumbering::Msb => (
+            quote!(FromMsb),
+            quote!(from_msb),
+            quote!(MsbInto),
+            quote!(msb_into),
+        ),
+    };
+    let partition = if fields.len() <= MAX_ARITY {
+        let partition = format_ident!("P{}", fields.len());
+        quote! {
+            let (#(#bindings,)*) = ::heterob::bit_numbering::#into_trait::#into_fn(
+                ::heterob::#partition::<#source, #({ #widths },)*>(data)
+            );
+        }
+    } else {
+        // Partition wrappers are limited to MAX_ARITY fields, so longer ones are HCons lists
+        let (list, pattern) = fields.iter().zip(&widths).zip(&bindings).rev().fold(
+            (quote!(::heterob::HNil), quote!(::heterob::HNil)),
+            |(list, pattern), ((field, width), binding)| {
+                let ty = &field.ty;
+                (
+                    quote!(::heterob::HCons<#ty, { #width }, #list>),
+                    quote!(::heterob::HCons(#binding, #pattern)),
+                )
+            },
+        );
+        quote! {
+            let #pattern: #list = ::heterob::bit_numbering::#into_trait::#into_fn(data);
+        }
+    };
+    Ok(quote! {
+        impl ::heterob::bit_numbering::#trait_name<#source> for #name {
+            fn #fn_name(data: #source) -> Self {
+                #partition
+                #constructor
+            }
+        }
+    })
+}
@@ -0,0 +1,277 @@
+use heterob::{
+    bit_numbering::{LsbInto, MsbInto},
+    endianness::{BeBytesInto, FromLeBytesSlice, LeBytesInto},
+    Seq, U8,
+};
+use heterob_derive::{FromBeBytes, FromLeBytes, FromLsb, FromMsb};
+
+#[derive(Debug, Clone, PartialEq, Eq, FromLeBytes, FromBeBytes)]
+struct Header {
+    byte: u8,
+    word: u16,
+    #[bytes(3)]
+    bytes: [u8; 3],
+}
+
+#[test]
+fn named_struct_from_bytes() {
+    let data = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55];
+
+    let result: Header = data.le_bytes_into();
+    let sample = Header {
+        byte: 0x00,
+        word: 0x2211,
+        bytes: [0x33, 0x44, 0x55],
+    };
+    assert_eq!(sample, result, "little endian");
+
+    let result: Header = data.be_bytes_into();
+    let sample = Header {
+        byte: 0x00,
+        word: 0x1122,
+        bytes: [0x33, 0x44, 0x55],
+    };
+    assert_eq!(sample, result, "big endian");
+}
+
+#[test]
+fn field_endianness_override() {
+    #[derive(Debug, PartialEq, Eq, FromLeBytes)]
+    struct Le(u16, #[be] u16, [u16; 2]);
+
+    #[derive(Debug, PartialEq, Eq, FromBeBytes)]
+    struct Be(u16, #[le] u16, [u16; 2]);
+
+    let data = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77];
+    assert_eq!(
+        Le(0x1100, 0x2233, [0x5544, 0x7766]),
+        data.le_bytes_into(),
+        "little endian"
+    );
+    assert_eq!(
+        Be(0x0011, 0x3322, [0x4455, 0x6677]),
+        data.be_bytes_into(),
+        "big endian"
+    );
+}
+
+#[test]
+fn nested_struct_from_bytes() {
+    #[derive(Debug, PartialEq, Eq, FromLeBytes)]
+    struct Outer {
+        #[bytes(6)]
+        header: Header,
+        tail: u16,
+    }
+
+    let data = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77];
+    let result: Outer = data.le_bytes_into();
+    let sample = Outer {
+        header: Header {
+            byte: 0x00,
+            word: 0x2211,
+            bytes: [0x33, 0x44, 0x55],
+        },
+        tail: 0x7766,
+    };
+    assert_eq!(sample, result);
+}
+
+#[test]
+fn nested_struct_default_width() {
+    // Header has padding, so its wire size (6) differs from its memory size
+    #[derive(Debug, PartialEq, Eq, FromBeBytes)]
+    struct Outer {
+        #[le]
+        header: Header,
+        byte: u8,
+        tail: u16,
+    }
+
+    let data = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88];
+    let result: Outer = data.be_bytes_into();
+    let sample = Outer {
+        header: Header {
+            byte: 0x00,
+            word: 0x2211,
+            bytes: [0x33, 0x44, 0x55],
+        },
+        byte: 0x66,
+        tail: 0x7788,
+    };
+    assert_eq!(sample, result);
+}
+
+#[test]
+fn crate_root_reexport() {
+    #[derive(Debug, PartialEq, Eq, heterob::FromLeBytes, heterob::FromMsb)]
+    #[bits(u16)]
+    struct Pair {
+        #[bits(8)]
+        a: u8,
+        #[bits(8)]
+        b: u8,
+    }
+
+    assert_eq!(Pair { a: 0x00, b: 0x11 }, [0x00, 0x11].le_bytes_into());
+    assert_eq!(Pair { a: 0x11, b: 0x00 }, 0x1100u16.msb_into());
+}
+
+#[test]
+fn array_of_structs_from_bytes() {
+    let data = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC];
+
+    let result: [Header; 2] = <[u8; 12]>::try_from(&data[..12]).unwrap().le_bytes_into();
+    let sample = [
+        Header {
+            byte: 0x00,
+            word: 0x2211,
+            bytes: [0x33, 0x44, 0x55],
+        },
+        Header {
+            byte: 0x66,
+            word: 0x8877,
+            bytes: [0x99, 0xAA, 0xBB],
+        },
+    ];
+    assert_eq!(sample, result, "array");
+
+    let Seq { head, tail } = <[Header; 2]>::from_le_bytes_slice(&data).unwrap();
+    assert_eq!(sample, head, "slice");
+    assert_eq!(&[0xCC], tail);
+}
+
+#[test]
+fn struct_from_bits() {
+    #[derive(Debug, PartialEq, Eq)]
+    enum Kind {
+        A,
+        B,
+    }
+    impl From<u8> for Kind {
+        fn from(byte: u8) -> Self {
+            if byte == 0 {
+                Self::A
+            } else {
+                Self::B
+            }
+        }
+    }
+
+    #[derive(Debug, PartialEq, Eq, FromLsb, FromMsb)]
+    #[bits(u16)]
+    struct Bits {
+        flag: bool,
+        #[bits(3)]
+        kind: U8<Kind>,
+        #[bits(4)]
+        _reserved: (),
+        byte: u8,
+    }
+
+    let data = 0b1000_1010_0000_0001u16;
+
+    let result: Bits = data.lsb_into();
+    let sample = Bits {
+        flag: true,
+        kind: U8(Kind::A),
+        _reserved: (),
+        byte: 0b1000_1010,
+    };
+    assert_eq!(sample, result, "LSB 0");
+
+    let result: Bits = data.msb_into();
+    let sample = Bits {
+        flag: true,
+        kind: U8(Kind::A),
+        _reserved: (),
+        byte: 0b0000_0001,
+    };
+    assert_eq!(sample, result, "MSB 0");
+}
+
+#[test]
+fn struct_from_bits_of_byte_array() {
+    #[derive(Debug, PartialEq, Eq, FromMsb)]
+    #[bits([u8; 8])]
+    struct Frame {
+        #[bits(12)]
+        id: u16,
+        is_remote: bool,
+        #[bits(3)]
+        _reserved: (),
+        length: u8,
+        payload: u32,
+        crc: u8,
+    }
+
+    let data = [0x12, 0x38, 0x04, 0xDE, 0xAD, 0xBE, 0xEF, 0x55];
+    let sample = Frame {
+        id: 0x123,
+        is_remote: true,
+        _reserved: (),
+        length: 4,
+        payload: 0xDEADBEEF,
+        crc: 0x55,
+    };
+    assert_eq!(sample, data.msb_into());
+}
+
+#[test]
+fn struct_from_bits_over_max_arity() {
+    #[derive(Debug, Default, PartialEq, Eq, FromLsb, FromMsb)]
+    #[bits(u32)]
+    struct Flags {
+        f0: bool,
+        f1: bool,
+        f2: bool,
+        f3: bool,
+        f4: bool,
+        f5: bool,
+        f6: bool,
+        f7: bool,
+        f8: bool,
+        f9: bool,
+        f10: bool,
+        f11: bool,
+        f12: bool,
+        f13: bool,
+        f14: bool,
+        f15: bool,
+        f16: bool,
+        f17: bool,
+        f18: bool,
+        f19: bool,
+        f20: bool,
+        f21: bool,
+        f22: bool,
+        f23: bool,
+        f24: bool,
+        f25: bool,
+        f26: bool,
+        #[bits(3)]
+        _reserved: (),
+        #[bits(2)]
+        kind: u8,
+    }
+
+    let data = 0b1000_0100_0000_0000_0000_0000_0000_0001u32;
+
+    let result: Flags = data.lsb_into();
+    let sample = Flags {
+        f0: true,
+        f26: true,
+        kind: 0b10,
+        ..Default::default()
+    };
+    assert_eq!(sample, result, "LSB 0");
+
+    let result: Flags = data.msb_into();
+    let sample = Flags {
+        f0: true,
+        f5: true,
+        kind: 0b01,
+        ..Default::default()
+    };
+    assert_eq!(sample, result, "MSB 0");
+}
@@ -57,32 +57,250 @@ let sample = (11, 2, 3);
 
 assert_eq!(sample, (a, b, c));
 ```
+
+User types implementing [FromBits] could be used without wrappers
+```rust
+# use heterob::{P3, bit_numbering::*};
+#[derive(Debug, PartialEq, Eq)]
+struct I4(i8);
+// Sign extension of field with any width
+impl<const W: usize> FromBits<W> for I4 {
+    fn from_bits(bits: u128) -> Self {
+        // Zero width field has no sign bit, shift by 128 bits would overflow
+        if W == 0 {
+            return Self(0);
+        }
+        Self(((bits << (128 - W)) as i128 >> (128 - W)) as i8)
+    }
+}
+
+let (a, b, c) = P3::<u8, 4, 0, 4>(0b0111_1110).lsb_into();
+assert_eq!((I4(-2), I4(0), I4(7)), (a, b, c));
+```
+
+Partitions of byte and word arrays are not limited to 128 bits
+```rust
+# use heterob::{P3, bit_numbering::*};
+let data: [u8; 9] = [0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0];
+
+let (a, (), b): (u8, (), u8) = P3::<_, 4, 64, 4>(data).lsb_into();
+assert_eq!((0xF, 0xF), (a, b));
+
+let (a, (), b): (u8, (), u8) = P3::<_, 4, 64, 4>(data).msb_into();
+assert_eq!((0x0, 0x0), (a, b));
+```
+
+Words order of arrays could be fixed with [Lsb0] / [Msb0] wrappers
+```rust
+# use heterob::{P2, bit_numbering::*};
+// Little endian 64 bits integer in 32 bits words
+let data = Lsb0([0x0000_00FFu32, 0xA000_0000]);
+
+let (a, b): (u8, u32) = P2::<_, 4, 60>(data).msb_into();
+assert_eq!((0xA, 0xFF), (a, b));
+```
+
+Fields wider than 128 bits fail to compile
+```compile_fail
+# use heterob::{P2, bit_numbering::*};
+let ((), ()) = P2::<_, 129, 7>([0u8; 17]).lsb_into();
+```
+
+Field layout is available in const contexts
+```rust
+# use heterob::P4;
+type Register = P4<u32, 3, 5, 8, 16>;
+
+const STATUS_MASK: u128 = Register::MASKS_LSB[2];
+assert_eq!(0x0000_FF00, STATUS_MASK);
+assert_eq!(0x00FF_0000, Register::MASKS_MSB[2]);
+assert_eq!([0, 3, 8, 16], Register::OFFSETS);
+assert_eq!([3, 5, 8, 16], Register::WIDTHS);
+```
+
+Const fn partitions of integers for compile time tables
+```rust
+# use heterob::{P2, P3, bit_numbering::*};
+const FLAGS: u16 = 0b1111_0000_1100_1010;
+const FIELDS: (u8, bool, u8) = {
+    let [a, b, c] = P3::<u16, 7, 1, 8>(FLAGS).lsb_fields();
+    (a as u8, b != 0, c as u8)
+};
+assert_eq!((0b100_1010, true, 0xF0), FIELDS);
+
+const SPLIT: (u16, u16) = msb_split_u16::<4>(FLAGS);
+assert_eq!((0b1111, 0b0000_1100_1010_0000), SPLIT);
+
+// Fields of signed integers are bits reinterpreted as unsigned
+const HDR: (i16, u32) = {
+    let [kind, length] = P2::<i32, 16, 16>(-2).lsb_fields();
+    (kind as i16, length as u32)
+};
+assert_eq!((-2, 0xFFFF), HDR);
+```
+
+Partial partition could be continued with remaining bits of [BitSeq]
+```rust
+# use heterob::{P1, P2, bit_numbering::*};
+let data: u16 = 0b1010_0101_1111_0001;
+
+let BitSeq { head: (kind,), tail, bits } = P1::<_, 4>(data).lsb_into();
+assert_eq!((1u8, 12), (kind, bits));
+
+// Layout of the rest depends on the kind field value
+let (a, b): (u8, u8) = match kind {
+    1 => P2::<_, 4, 8>(tail).lsb_into(),
+    _ => P2::<_, 8, 4>(tail).lsb_into(),
+};
+assert_eq!((0b1111, 0b1010_0101), (a, b));
+```
+
+Single field could be replaced keeping the rest of bits
+```rust
+# use heterob::{P4, bit_numbering::*};
+let register: u32 = 0xFFFF_00FF;
+
+let P4(result) = P4::<u32, 3, 5, 8, 16>(register).lsb_with::<2>(0xA5);
+assert_eq!(0xFFFF_A5FF, result);
+
+let (_, _, field, _): (u8, u8, u8, u16) = P4::<u32, 3, 5, 8, 16>(result).lsb_into();
+assert_eq!(0xA5, field);
+
+// Values wider than field are rejected by checked replacement
+assert_eq!(Err(0x1A5), P4::<u32, 3, 5, 8, 16>(register).try_lsb_with::<2>(0x1A5));
+```
+
+Field index out of partition bounds fails to compile
+```compile_fail
+# use heterob::{P4, bit_numbering::*};
+let _ = P4::<u32, 3, 5, 8, 16>(0).lsb_with::<4>(0);
+```
+
+Scattered bit ranges could be selected with [Bits] selectors and concatenated with [Gather]
+```rust
+# use heterob::bit_numbering::*;
+// RISC-V S-type instruction: sw x5, 1234(x10)
+let instruction: u32 = 0x4c55_2923;
+
+let (rs1, rs2, imm): (u8, u8, u16) = instruction.select::<(
+    Bits<15, 5>,
+    Bits<20, 5>,
+    // imm[4:0] = instruction[11:7], imm[11:5] = instruction[31:25]
+    Gather<(Bits<7, 5>, Bits<25, 7>), 12>,
+), _>();
+assert_eq!((10, 5, 1234), (rs1, rs2, imm));
+```
+
+Selection out of source bounds fails to compile
+```compile_fail
+# use heterob::bit_numbering::*;
+let _: u8 = 0u8.select::<Bits<4, 5>, _>();
+```
+
+Gather width that differs from sum of its selectors widths fails to compile
+```compile_fail
+# use heterob::bit_numbering::*;
+let _: u8 = 0u8.select::<Gather<(Bits<0, 2>, Bits<4, 2>), 3>, _>();
+```
+
+Checked conversion rejects values that don't fit into resulting types
+```rust
+# use heterob::{P3, T3Error, bit_numbering::*};
+let data: u16 = 0b101_0000_0000_1011;
+
+let result: Result<(u8, u8, u8), _> = P3::<_, 2, 11, 3>(data).try_lsb_into();
+assert_eq!(Err(T3Error::B(0b100_0000_0010)), result);
+
+let result: Result<(u8, u16, u8), _> = P3::<_, 2, 11, 3>(data).try_lsb_into();
+assert_eq!(Ok((0b11, 0b100_0000_0010, 0b10)), result);
+```
+
+Heterogeneous list [HCons] has no 26 fields limit of tuple wrappers. It destructures to flat
+tuple with [IntoTuple](crate::IntoTuple).
+```rust
+# use heterob::{HCons, HNil, IntoTuple, bit_numbering::*};
+type L = HCons<u8, 4, HCons<(), 10, HCons<u8, 2, HNil>>>;
+
+let list: L = 0b1011_0000_0000_1011u16.lsb_into();
+let (a, (), b) = list.into_tuple();
+assert_eq!((0b1011, 0b10), (a, b));
+
+let list: L = [0b1011_0000u8, 0b0000_1011].msb_into();
+assert_eq!((0b1011, (), 0b11), list.into_tuple(), "array source");
+```
 */
 
 
-use core::mem::size_of;
+use core::{marker::PhantomData, mem::size_of, ops::Range};
 use paste::paste;
 
 use funty::Integral;
 
+use crate::layout;
+
 use super::*;
 
+#[cfg(feature = "derive")]
+pub use heterob_derive::{FromLsb, FromMsb};
+
 
 macro_rules! bit_numbering_alphabet {
     ($sb:ident => $len:literal: $($cl:ident),+ $(,)?) => { paste!{
         // impl<TY, A, .. , const AN: usize, .. > FromLsb<P#<TY, .. >> for (A, .. )
         impl<TY, $($cl,)+ $(const [<$cl N>]: usize,)+> [<From $sb>]<[<P $len>]<TY, $([<$cl N>],)+>> for ($($cl,)+)
         where
-            TY: Integral $(+ AsPrimitive<$cl>)+,
+            TY: BitSource,
+            $($cl: FromBits<[<$cl N>]>,)+
         {
-            const BITS: usize = TY::BITS as usize;
+            const BITS: usize = TY::BITS;
             const MAX_BIT_INDEX: usize = 0 $(+ [<$cl N>])+;
+            const ASSERT_INDEX_IN_BOUNDS: usize = {
+                // Fields are read into u128
+                $(let _ = u128::BITS as usize - [<$cl N>];)+
+                TY::BITS - (0 $(+ [<$cl N>])+)
+            };
             fn [<from_ $sb:lower>]([<P $len>](_data): [<P $len>]<TY, $([<$cl N>],)+>) -> Self {
                 #![allow(path_statements)]
                 <Self as [<From $sb>]<[<P $len>]<TY, $([<$cl N>],)+>>>::ASSERT_INDEX_IN_BOUNDS;
 
-                $(let ([<$cl:lower>], _data) = [<$sb:lower _split>]::<_, [<$cl N>]>(_data);)+
-                ($([<$cl:lower>].as_primitive(),)+)
+                let _index = 0;
+                $(
+                    let [<$cl:lower>] = _data.[<$sb:lower _bits>](_index, [<$cl N>]);
+                    let _index = _index + [<$cl N>];
+                )+
+                ($($cl::from_bits([<$cl:lower>]),)+)
+            }
+        }
+
+        // impl<TY, A, .. , const AN: usize, .. > TryFromLsb<P#<TY, .. >> for (A, .. )
+        impl<TY, $($cl,)+ $(const [<$cl N>]: usize,)+> [<TryFrom $sb>]<[<P $len>]<TY, $([<$cl N>],)+>> for ($($cl,)+)
+        where
+            TY: BitSource,
+            $(u128: TryAsPrimitive<$cl>,)+
+        {
+            type Error = [<T $len Error>]<$(<u128 as TryAsPrimitive<$cl>>::Error,)+>;
+            const BITS: usize = TY::BITS;
+            const MAX_BIT_INDEX: usize = 0 $(+ [<$cl N>])+;
+            const ASSERT_INDEX_IN_BOUNDS: usize = {
+                // Fields are read into u128
+                $(let _ = u128::BITS as usize - [<$cl N>];)+
+                TY::BITS - (0 $(+ [<$cl N>])+)
+            };
+            fn [<try_from_ $sb:lower>]([<P $len>](_data): [<P $len>]<TY, $([<$cl N>],)+>) ->
+                Result<Self, Self::Error>
+            {
+                #![allow(path_statements)]
+                <Self as [<TryFrom $sb>]<[<P $len>]<TY, $([<$cl N>],)+>>>::ASSERT_INDEX_IN_BOUNDS;
+
+                let _index = 0;
+                $(
+                    let [<$cl:lower>] = _data
+                        .[<$sb:lower _bits>](_index, [<$cl N>])
+                        .try_as_primitive()
+                        .map_err([<T $len Error>]::$cl)?;
+                    let _index = _index + [<$cl N>];
+                )+
+                Ok(($([<$cl:lower>],)+))
             }
         }
 
@@ -103,6 +321,402 @@ macro_rules! bit_numbering_alphabet {
 }
 
 
+/// Value from bit field of `W` bits width
+///
+/// Extension point used by [FromLsb] / [FromMsb] implementations of partitions for every
+/// field. Raw field bits are passed in least significant bits of [u128].
+///
+/// It is implemented for all types [AsPrimitive] converts [u128] to.
+pub trait FromBits<const W: usize>: Sized {
+    fn from_bits(bits: u128) -> Self;
+}
+
+impl<T, const W: usize> FromBits<W> for T
+where
+    u128: AsPrimitive<T>,
+{
+    fn from_bits(bits: u128) -> Self {
+        bits.as_primitive()
+    }
+}
+
+/// Source of bits for partitions
+///
+/// Implemented for integers and arrays of bit sources. Array elements are taken in order,
+/// bits within every element are numbered according to bit numbering of conversion, so
+/// LSB 0 partition of `[u8; N]` is partition of little endian integer and MSB 0 one is
+/// partition of big endian integer. Wrap array into [Lsb0] or [Msb0] to keep the same words
+/// order for both bit numberings.
+///
+/// Fields are read into [u128], so fields wider than 128 bits fail to compile.
+pub trait BitSource {
+    /// Total number of bits
+    const BITS: usize;
+    /// Field of `n` bits starting from bit `index` in LSB 0 bit numbering
+    fn lsb_bits(&self, index: usize, n: usize) -> u128;
+    /// Field of `n` bits starting from bit `index` in MSB 0 bit numbering
+    fn msb_bits(&self, index: usize, n: usize) -> u128;
+    /// Replace field of `n` bits starting from bit `index` in LSB 0 bit numbering with low bits
+    /// of `value`
+    fn set_lsb_bits(&mut self, index: usize, n: usize, value: u128);
+    /// Replace field of `n` bits starting from bit `index` in MSB 0 bit numbering with low bits
+    /// of `value`
+    fn set_msb_bits(&mut self, index: usize, n: usize, value: u128);
+    /// Bits following the first `n` bits moved to the beginning in LSB 0 bit numbering,
+    /// vacated bits are zeros
+    fn lsb_skip(&self, n: usize) -> Self
+    where
+        Self: Sized + Clone,
+    {
+        let mut tail = self.clone();
+        let mut index = 0;
+        while index < Self::BITS {
+            let w = (Self::BITS - index).min(u128::BITS as usize);
+            let from = index.saturating_add(n);
+            let available = Self::BITS.saturating_sub(from).min(w);
+            tail.set_lsb_bits(index, w, self.lsb_bits(from, available));
+            index += w;
+        }
+        tail
+    }
+    /// Bits following the first `n` bits moved to the beginning in MSB 0 bit numbering,
+    /// vacated bits are zeros
+    fn msb_skip(&self, n: usize) -> Self
+    where
+        Self: Sized + Clone,
+    {
+        let mut tail = self.clone();
+        let mut index = 0;
+        while index < Self::BITS {
+            let w = (Self::BITS - index).min(u128::BITS as usize);
+            let from = index.saturating_add(n);
+            let available = Self::BITS.saturating_sub(from).min(w);
+            tail.set_msb_bits(index, w, shl128(self.msb_bits(from, available), w - available));
+            index += w;
+        }
+        tail
+    }
+    /// Values of bits selected with [Selector] or tuple of selectors
+    fn select<S, T: FromSelection<Self, S>>(&self) -> T
+    where
+        Self: Sized,
+    {
+        T::from_selection(self)
+    }
+}
+
+/// Logical right shift that results in zero when all bits are shifted out
+fn shr128(data: u128, n: usize) -> u128 {
+    u32::try_from(n)
+        .ok()
+        .and_then(|n| data.checked_shr(n))
+        .unwrap_or(0)
+}
+
+/// Left shift that results in zero when all bits are shifted out
+fn shl128(data: u128, n: usize) -> u128 {
+    u32::try_from(n)
+        .ok()
+        .and_then(|n| data.checked_shl(n))
+        .unwrap_or(0)
+}
+
+/// Lowest `n` bits
+fn low_bits(data: u128, n: usize) -> u128 {
+    data & !shl128(u128::MAX, n)
+}
+
+macro_rules! impl_bit_source {
+    ($($ty:ty),+ $(,)?) => { $(
+        impl BitSource for $ty {
+            const BITS: usize = <$ty>::BITS as usize;
+            fn lsb_bits(&self, index: usize, n: usize) -> u128 {
+                low_bits(shr128(bits_u128(*self), index), n)
+            }
+            fn msb_bits(&self, index: usize, n: usize) -> u128 {
+                let index = <Self as BitSource>::BITS.saturating_sub(index.saturating_add(n));
+                self.lsb_bits(index, n)
+            }
+            fn set_lsb_bits(&mut self, index: usize, n: usize, value: u128) {
+                *self = set_bits(*self, index, n, value as $ty);
+            }
+            fn set_msb_bits(&mut self, index: usize, n: usize, value: u128) {
+                let index = <Self as BitSource>::BITS.saturating_sub(index.saturating_add(n));
+                self.set_lsb_bits(index, n, value)
+            }
+            fn lsb_skip(&self, n: usize) -> Self {
+                logical_shr(*self, n)
+            }
+            fn msb_skip(&self, n: usize) -> Self {
+                shl(*self, n)
+            }
+        }
+    )+ };
+}
+
+impl_bit_source!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl<W: BitSource, const N: usize> BitSource for [W; N] {
+    const BITS: usize = W::BITS * N;
+    fn lsb_bits(&self, index: usize, n: usize) -> u128 {
+        let mut value = 0;
+        let mut done = 0;
+        while done < n {
+            let bit = index + done;
+            let Some(word) = self.get(bit / W::BITS) else { break };
+            let offset = bit % W::BITS;
+            let take = (W::BITS - offset).min(n - done);
+            value |= shl128(word.lsb_bits(offset, take), done);
+            done += take;
+        }
+        value
+    }
+    fn msb_bits(&self, index: usize, n: usize) -> u128 {
+        let mut value = 0;
+        let mut done = 0;
+        while done < n {
+            let bit = index + done;
+            let Some(word) = self.get(bit / W::BITS) else { break };
+            let offset = bit % W::BITS;
+            let take = (W::BITS - offset).min(n - done);
+            value = shl128(value, take) | word.msb_bits(offset, take);
+            done += take;
+        }
+        value
+    }
+    fn set_lsb_bits(&mut self, index: usize, n: usize, value: u128) {
+        let mut done = 0;
+        while done < n {
+            let bit = index + done;
+            let Some(word) = self.get_mut(bit / W::BITS) else { break };
+            let offset = bit % W::BITS;
+            let take = (W::BITS - offset).min(n - done);
+            word.set_lsb_bits(offset, take, shr128(value, done));
+            done += take;
+        }
+    }
+    fn set_msb_bits(&mut self, index: usize, n: usize, value: u128) {
+        let mut done = 0;
+        while done < n {
+            let bit = index + done;
+            let Some(word) = self.get_mut(bit / W::BITS) else { break };
+            let offset = bit % W::BITS;
+            let take = (W::BITS - offset).min(n - done);
+            word.set_msb_bits(offset, take, shr128(value, n - done - take));
+            done += take;
+        }
+    }
+}
+
+/// Bit source with least significant bits in the first word for both bit numberings
+///
+/// LSB 0 partition of `Lsb0([W; N])` is the same as one of `[W; N]`, MSB 0 partition starts
+/// from most significant bits of the last word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Lsb0<T>(pub T);
+
+/// Bit source with most significant bits in the first word for both bit numberings
+///
+/// MSB 0 partition of `Msb0([W; N])` is the same as one of `[W; N]`, LSB 0 partition starts
+/// from least significant bits of the last word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Msb0<T>(pub T);
+
+impl<T: BitSource> BitSource for Lsb0<T> {
+    const BITS: usize = T::BITS;
+    fn lsb_bits(&self, index: usize, n: usize) -> u128 {
+        self.0.lsb_bits(index, n)
+    }
+    fn msb_bits(&self, index: usize, n: usize) -> u128 {
+        let index = T::BITS.saturating_sub(index.saturating_add(n));
+        self.0.lsb_bits(index, n)
+    }
+    fn set_lsb_bits(&mut self, index: usize, n: usize, value: u128) {
+        self.0.set_lsb_bits(index, n, value)
+    }
+    fn set_msb_bits(&mut self, index: usize, n: usize, value: u128) {
+        let index = T::BITS.saturating_sub(index.saturating_add(n));
+        self.0.set_lsb_bits(index, n, value)
+    }
+}
+
+impl<T: BitSource> BitSource for Msb0<T> {
+    const BITS: usize = T::BITS;
+    fn lsb_bits(&self, index: usize, n: usize) -> u128 {
+        let index = T::BITS.saturating_sub(index.saturating_add(n));
+        self.0.msb_bits(index, n)
+    }
+    fn msb_bits(&self, index: usize, n: usize) -> u128 {
+        self.0.msb_bits(index, n)
+    }
+    fn set_lsb_bits(&mut self, index: usize, n: usize, value: u128) {
+        let index = T::BITS.saturating_sub(index.saturating_add(n));
+        self.0.set_msb_bits(index, n, value)
+    }
+    fn set_msb_bits(&mut self, index: usize, n: usize, value: u128) {
+        self.0.set_msb_bits(index, n, value)
+    }
+}
+
+/// Selector of `W` bits starting from bit `LO` in LSB 0 bit numbering
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Bits<const LO: usize, const W: usize>;
+
+/// Selector that concatenates tuple of selectors into `W` bits value, first one becomes least
+/// significant bits
+///
+/// `W` should be equal to sum of selectors widths, it is checked at compile time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Gather<T, const W: usize>(PhantomData<T>);
+
+/// Selection of bits from [BitSource]
+pub trait Selector {
+    /// Number of selected bits
+    const WIDTH: usize;
+    /// Index of bit following the last selected one
+    const END: usize;
+    fn lsb_select<TY: BitSource>(data: &TY) -> u128;
+}
+
+impl<const LO: usize, const W: usize> Selector for Bits<LO, W> {
+    const WIDTH: usize = W;
+    const END: usize = LO + W;
+    fn lsb_select<TY: BitSource>(data: &TY) -> u128 {
+        data.lsb_bits(LO, W)
+    }
+}
+
+/// Values from bits selected with `S` selectors
+///
+/// Implemented for [FromBits] types with [Bits] or [Gather] selector and for tuples of such
+/// types with tuples of selectors. Selections out of source bounds fail to compile.
+pub trait FromSelection<TY: BitSource, S>: Sized {
+    /// Index of bit following the last selected one
+    const END: usize;
+    const ASSERT_INDEX_IN_BOUNDS: usize = TY::BITS - Self::END;
+    fn from_selection(data: &TY) -> Self;
+}
+
+impl<TY: BitSource, A: FromBits<W>, const LO: usize, const W: usize> FromSelection<TY, Bits<LO, W>>
+    for A
+{
+    const END: usize = LO + W;
+    const ASSERT_INDEX_IN_BOUNDS: usize = {
+        // Selection is read into u128
+        let _ = u128::BITS as usize - W;
+        TY::BITS - (LO + W)
+    };
+    fn from_selection(data: &TY) -> Self {
+        #![allow(path_statements)]
+        <Self as FromSelection<TY, Bits<LO, W>>>::ASSERT_INDEX_IN_BOUNDS;
+
+        A::from_bits(Bits::<LO, W>::lsb_select(data))
+    }
+}
+
+impl<TY: BitSource, T, A: FromBits<W>, const W: usize> FromSelection<TY, Gather<T, W>> for A
+where
+    Gather<T, W>: Selector,
+{
+    const END: usize = <Gather<T, W> as Selector>::END;
+    const ASSERT_INDEX_IN_BOUNDS: usize = {
+        // Selection is read into u128
+        let _ = u128::BITS as usize - W;
+        TY::BITS - <Gather<T, W> as Selector>::END
+    };
+    fn from_selection(data: &TY) -> Self {
+        #![allow(path_statements)]
+        <Self as FromSelection<TY, Gather<T, W>>>::ASSERT_INDEX_IN_BOUNDS;
+
+        A::from_bits(Gather::<T, W>::lsb_select(data))
+    }
+}
+
+const fn max(a: usize, b: usize) -> usize {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
+macro_rules! bit_selection_alphabet {
+    ($len:literal: $($cl:ident),+ $(,)?) => { paste!{
+        /*
+        impl<A: Selector, B: Selector, C: Selector, const NU: usize> Gather<(A, B, C), NU> {
+            const SUM: usize = A::WIDTH + B::WIDTH + C::WIDTH;
+            const ASSERT_WIDTH_EQ: usize = (NU - Self::SUM) + (Self::SUM - NU);
+        }
+        impl<A: Selector, B: Selector, C: Selector, const NU: usize> Selector for Gather<(A, B, C), NU> {
+            const WIDTH: usize = NU;
+            const END: usize = max(max(max(0, A::END), B::END), C::END);
+            fn lsb_select<TY: BitSource>(data: &TY) -> u128 {
+                #![allow(path_statements)]
+                Self::ASSERT_WIDTH_EQ;
+
+                let mut value = 0;
+                let mut _shift = 0;
+                value |= shl128(A::lsb_select(data), _shift);
+                _shift += A::WIDTH;
+                ..
+                value
+            }
+        }
+        */
+        impl<$($cl: Selector,)+ const NU: usize> Gather<($($cl,)+), NU> {
+            const SUM: usize = 0 $(+ $cl::WIDTH)+;
+            const ASSERT_WIDTH_EQ: usize = (NU - Self::SUM) + (Self::SUM - NU);
+        }
+        impl<$($cl: Selector,)+ const NU: usize> Selector for Gather<($($cl,)+), NU> {
+            const WIDTH: usize = NU;
+            const END: usize = {
+                let end = 0;
+                $(let end = max(end, $cl::END);)+
+                end
+            };
+            fn lsb_select<TY: BitSource>(data: &TY) -> u128 {
+                #![allow(path_statements)]
+                Self::ASSERT_WIDTH_EQ;
+
+                let mut value = 0;
+                let mut _shift = 0;
+                $(
+                    value |= shl128($cl::lsb_select(data), _shift);
+                    _shift += $cl::WIDTH;
+                )+
+                value
+            }
+        }
+
+        /*
+        impl<TY: BitSource, A, B, C, AS, BS, CS> FromSelection<TY, (AS, BS, CS)> for (A, B, C)
+        where
+            A: FromSelection<TY, AS>,
+            B: FromSelection<TY, BS>,
+            C: FromSelection<TY, CS>,
+        {
+            const END: usize = max(max(max(0, A::END), B::END), C::END);
+            fn from_selection(data: &TY) -> Self {
+                (A::from_selection(data), B::from_selection(data), C::from_selection(data))
+            }
+        }
+        */
+        impl<TY: BitSource, $($cl, [<$cl S>],)+> FromSelection<TY, ($([<$cl S>],)+)> for ($($cl,)+)
+        where
+            $($cl: FromSelection<TY, [<$cl S>]>,)+
+        {
+            const END: usize = {
+                let end = 0;
+                $(let end = max(end, <$cl as FromSelection<TY, [<$cl S>]>>::END);)+
+                end
+            };
+            fn from_selection(data: &TY) -> Self {
+                ($(<$cl as FromSelection<TY, [<$cl S>]>>::from_selection(data),)+)
+            }
+        }
+    }};
+}
+
 /// Type wrapper for LSB 0 bit numbering data
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Lsb<T>(pub T);
@@ -133,6 +747,36 @@ impl<T, U: FromLsb<T>> LsbInto<U> for T {
     }
 }
 
+/// Fallible LSB 0 bit numbering data to value conversion
+///
+/// Rejects values that don't fit into resulting types, see [TryAsPrimitive].
+/// It is the reciprocal of [TryLsbInto].
+pub trait TryFromLsb<T: Sized>: Sized {
+    type Error;
+    const BITS: usize = size_of::<T>() * 8;
+    const MAX_BIT_INDEX: usize = Self::BITS - 1;
+    const ASSERT_INDEX_IN_BOUNDS: usize = Self::BITS - Self::MAX_BIT_INDEX;
+    fn try_from_lsb(_: T) -> Result<Self, Self::Error>;
+}
+
+/// Fallible LSB 0 bit numbering data to value conversion that consumes the input bytes
+///
+/// The opposite of [TryFromLsb].
+/// One should avoid implementing [TryLsbInto] and implement [TryFromLsb] instead.
+pub trait TryLsbInto<T>: Sized {
+    type Error;
+    fn try_lsb_into(self) -> Result<T, Self::Error>;
+}
+
+/// Implementing [TryFromLsb] automatically provides one with an implementation of [TryLsbInto]
+/// thanks to this blanket implementation.
+impl<T, U: TryFromLsb<T>> TryLsbInto<U> for T {
+    type Error = U::Error;
+    fn try_lsb_into(self) -> Result<U, Self::Error> {
+        U::try_from_lsb(self)
+    }
+}
+
 /**
 Split integer at some point according to LSB 0 bit numbering
 
@@ -142,10 +786,423 @@ const U32: u32 = 0b1111_1111_0101_1010_1100_001__1_1000_0001;
 let result = lsb_split::<_, 9>(U32);
 assert_eq!((0b1_1000_0001, 0b1111_1111_0101_1010_1100_001), result);
 ```
+
+Signed integers are split as their unsigned counterparts
+```rust
+# use heterob::bit_numbering::lsb_split;
+let result = lsb_split::<_, 3>(-1i8);
+assert_eq!((0b111, 0b1_1111), result);
+```
 */
 pub fn lsb_split<T: Integral, const N: usize>(data: T) -> (T, T) {
-    let mask = !(T::MAX << N);
-    (data & mask, data >> N)
+    let mask = !shl(!T::ZERO, N);
+    (data & mask, logical_shr(data, N))
+}
+
+/// Left shift that results in zero when all bits are shifted out
+fn shl<T: Integral>(data: T, n: usize) -> T {
+    u32::try_from(n)
+        .ok()
+        .and_then(|n| data.checked_shl(n))
+        .unwrap_or(T::ZERO)
+}
+
+/// Right shift that fills high bits with zeros for signed integers too
+/// and results in zero when all bits are shifted out
+fn logical_shr<T: Integral>(data: T, n: usize) -> T {
+    match u32::try_from(n).ok().and_then(|n| data.checked_shr(n)) {
+        Some(value) => value & !shl(!T::ZERO, T::BITS as usize - n),
+        None => T::ZERO,
+    }
+}
+
+/// Bits of integer reinterpreted as unsigned
+fn bits_u128<T: Integral>(data: T) -> u128 {
+    data.as_u128() & (u128::MAX >> (u128::BITS - T::BITS))
+}
+
+/// Replace `n` bits of integer starting from bit `index` in LSB 0 bit numbering
+fn set_bits<T: Integral>(data: T, index: usize, n: usize, value: T) -> T {
+    let mask = shl(!shl(!T::ZERO, n), index);
+    (data & !mask) | (shl(value, index) & mask)
+}
+
+/// Compile time check of field bounds
+struct Field<T, const INDEX: usize, const N: usize>(PhantomData<T>);
+
+impl<T: Integral, const INDEX: usize, const N: usize> Field<T, INDEX, N> {
+    const ASSERT_INDEX_IN_BOUNDS: usize = T::BITS as usize - (INDEX + N);
+}
+
+/**
+Replace `N` bits of integer starting from bit `INDEX` according to LSB 0 bit numbering
+
+```rust
+# use heterob::bit_numbering::lsb_set;
+let register: u16 = 0b1111_0000_0000_1111;
+let result = lsb_set::<_, 4, 4>(register, 0b1010);
+assert_eq!(0b1111_0000_1010_1111, result);
+```
+*/
+pub fn lsb_set<T: Integral, const INDEX: usize, const N: usize>(data: T, value: T) -> T {
+    #![allow(path_statements)]
+    Field::<T, INDEX, N>::ASSERT_INDEX_IN_BOUNDS;
+
+    set_bits(data, INDEX, N, value)
+}
+
+/// Bit level sequence of decoded head and remaining bits
+///
+/// Remaining bits are shifted to the beginning of `tail` according to bit numbering, so they
+/// could be partitioned again.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitSeq<H, T> {
+    pub head: H,
+    pub tail: T,
+    /// Number of remaining bits
+    pub bits: usize,
+}
+
+macro_rules! bit_numbering_seq {
+    ($len:literal: $($cl:ident),+ $(,)?) => { paste!{
+        /*
+        impl<TY, H, const AN: usize, const BN: usize, const CN: usize> FromLsb<P3<TY, AN, BN, CN>>
+            for BitSeq<H, TY>
+        where
+            TY: BitSource + Clone,
+            H: FromLsb<P3<TY, AN, BN, CN>>,
+        {
+            const BITS: usize = <TY as BitSource>::BITS;
+            const MAX_BIT_INDEX: usize = AN + BN + CN;
+            fn from_lsb(data: P3<TY, AN, BN, CN>) -> Self {
+                #![allow(path_statements)]
+                <Self as FromLsb<P3<TY, AN, BN, CN>>>::ASSERT_INDEX_IN_BOUNDS;
+
+                let tail = data.0.lsb_skip(P3::<TY, AN, BN, CN>::SUM);
+                Self {
+                    head: data.lsb_into(),
+                    tail,
+                    bits: P3::<TY, AN, BN, CN>::REMAINING_BITS,
+                }
+            }
+        }
+        */
+        impl<TY, H, $(const [<$cl N>]: usize,)+> FromLsb<[<P $len>]<TY, $([<$cl N>],)+>>
+            for BitSeq<H, TY>
+        where
+            TY: BitSource + Clone,
+            H: FromLsb<[<P $len>]<TY, $([<$cl N>],)+>>,
+        {
+            const BITS: usize = <TY as BitSource>::BITS;
+            const MAX_BIT_INDEX: usize = 0 $(+ [<$cl N>])+;
+            fn from_lsb(data: [<P $len>]<TY, $([<$cl N>],)+>) -> Self {
+                #![allow(path_statements)]
+                <Self as FromLsb<[<P $len>]<TY, $([<$cl N>],)+>>>::ASSERT_INDEX_IN_BOUNDS;
+
+                let tail = data.0.lsb_skip([<P $len>]::<TY, $([<$cl N>],)+>::SUM);
+                Self {
+                    head: data.lsb_into(),
+                    tail,
+                    bits: [<P $len>]::<TY, $([<$cl N>],)+>::REMAINING_BITS,
+                }
+            }
+        }
+
+        impl<TY, H, $(const [<$cl N>]: usize,)+> FromMsb<[<P $len>]<TY, $([<$cl N>],)+>>
+            for BitSeq<H, TY>
+        where
+            TY: BitSource + Clone,
+            H: FromMsb<[<P $len>]<TY, $([<$cl N>],)+>>,
+        {
+            const BITS: usize = <TY as BitSource>::BITS;
+            const MAX_BIT_INDEX: usize = 0 $(+ [<$cl N>])+;
+            fn from_msb(data: [<P $len>]<TY, $([<$cl N>],)+>) -> Self {
+                #![allow(path_statements)]
+                <Self as FromMsb<[<P $len>]<TY, $([<$cl N>],)+>>>::ASSERT_INDEX_IN_BOUNDS;
+
+                let tail = data.0.msb_skip([<P $len>]::<TY, $([<$cl N>],)+>::SUM);
+                Self {
+                    head: data.msb_into(),
+                    tail,
+                    bits: [<P $len>]::<TY, $([<$cl N>],)+>::REMAINING_BITS,
+                }
+            }
+        }
+    }};
+}
+
+/// Mask of `n` bits starting from bit `index`
+pub(crate) const fn field_mask(index: usize, n: usize) -> u128 {
+    let mask = if n < u128::BITS as usize { (1 << n) - 1 } else { u128::MAX };
+    if index < u128::BITS as usize {
+        mask << index
+    } else {
+        0
+    }
+}
+
+macro_rules! bit_numbering_const {
+    (Split: $($ty:ty),+ $(,)?) => { paste!{ $(
+        /// Field of `n` bits starting from bit `index` in LSB 0 bit numbering
+        const fn [<const_bits_ $ty>](data: $ty, index: usize, n: usize) -> $ty {
+            let data = if index < <$ty>::BITS as usize { data >> index } else { 0 };
+            if n < <$ty>::BITS as usize {
+                data & !(<$ty>::MAX << n)
+            } else {
+                data
+            }
+        }
+
+        #[doc=concat!("Const fn [lsb_split] of [", stringify!($ty), "]")]
+        pub const fn [<lsb_split_ $ty>]<const N: usize>(data: $ty) -> ($ty, $ty) {
+            ([<const_bits_ $ty>](data, 0, N), [<const_bits_ $ty>](data, N, <$ty>::BITS as usize))
+        }
+
+        #[doc=concat!("Const fn [msb_split] of [", stringify!($ty), "]")]
+        pub const fn [<msb_split_ $ty>]<const N: usize>(data: $ty) -> ($ty, $ty) {
+            let index = (<$ty>::BITS as usize).saturating_sub(N);
+            let tail = if N < <$ty>::BITS as usize { data << N } else { 0 };
+            ([<const_bits_ $ty>](data, index, N), tail)
+        }
+    )+ }};
+    (Signed: $($ity:ty as $uty:ty),+ $(,)?) => { paste!{ $(
+        #[doc=concat!("Const fn [lsb_split] of [", stringify!($ity), "]")]
+        pub const fn [<lsb_split_ $ity>]<const N: usize>(data: $ity) -> ($ity, $ity) {
+            let (head, tail) = [<lsb_split_ $uty>]::<N>(data as $uty);
+            (head as $ity, tail as $ity)
+        }
+
+        #[doc=concat!("Const fn [msb_split] of [", stringify!($ity), "]")]
+        pub const fn [<msb_split_ $ity>]<const N: usize>(data: $ity) -> ($ity, $ity) {
+            let (head, tail) = [<msb_split_ $uty>]::<N>(data as $uty);
+            (head as $ity, tail as $ity)
+        }
+    )+ }};
+    ($ity:ty as $uty:ty => $len:literal: $($cl:ident),+ $(,)?) => { paste!{
+        impl<$(const [<$cl N>]: usize,)+> [<P $len>]<$ity, $([<$cl N>],)+> {
+            /// Const fn partition according to LSB 0 bit numbering
+            pub const fn lsb_fields(self) -> [$ity; $len] {
+                let unsigned = [<P $len>]::<$uty, $([<$cl N>],)+>(self.0 as $uty).lsb_fields();
+                let mut fields = [0; $len];
+                let mut i = 0;
+                while i < $len {
+                    fields[i] = unsigned[i] as $ity;
+                    i += 1;
+                }
+                fields
+            }
+
+            /// Const fn partition according to MSB 0 bit numbering
+            pub const fn msb_fields(self) -> [$ity; $len] {
+                let unsigned = [<P $len>]::<$uty, $([<$cl N>],)+>(self.0 as $uty).msb_fields();
+                let mut fields = [0; $len];
+                let mut i = 0;
+                while i < $len {
+                    fields[i] = unsigned[i] as $ity;
+                    i += 1;
+                }
+                fields
+            }
+        }
+    }};
+    ($ty:ty => $len:literal: $($cl:ident),+ $(,)?) => { paste!{
+        impl<$(const [<$cl N>]: usize,)+> [<P $len>]<$ty, $([<$cl N>],)+> {
+            /// Const fn partition according to LSB 0 bit numbering
+            #[allow(path_statements)]
+            pub const fn lsb_fields(self) -> [$ty; $len] {
+                Self::ASSERT_INDEX_IN_BOUNDS;
+
+                let mut fields = [0; $len];
+                let mut i = 0;
+                while i < $len {
+                    fields[i] = [<const_bits_ $ty>](self.0, Self::OFFSETS[i], Self::WIDTHS[i]);
+                    i += 1;
+                }
+                fields
+            }
+
+            /// Const fn partition according to MSB 0 bit numbering
+            #[allow(path_statements)]
+            pub const fn msb_fields(self) -> [$ty; $len] {
+                Self::ASSERT_INDEX_IN_BOUNDS;
+
+                let mut fields = [0; $len];
+                let mut i = 0;
+                while i < $len {
+                    let index = <$ty>::BITS as usize - Self::OFFSETS[i] - Self::WIDTHS[i];
+                    fields[i] = [<const_bits_ $ty>](self.0, index, Self::WIDTHS[i]);
+                    i += 1;
+                }
+                fields
+            }
+        }
+    }};
+    ($len:literal: $($cl:ident),+ $(,)?) => {
+        bit_numbering_const!(u8 => $len: $($cl),+);
+        bit_numbering_const!(u16 => $len: $($cl),+);
+        bit_numbering_const!(u32 => $len: $($cl),+);
+        bit_numbering_const!(u64 => $len: $($cl),+);
+        bit_numbering_const!(u128 => $len: $($cl),+);
+        bit_numbering_const!(i8 as u8 => $len: $($cl),+);
+        bit_numbering_const!(i16 as u16 => $len: $($cl),+);
+        bit_numbering_const!(i32 as u32 => $len: $($cl),+);
+        bit_numbering_const!(i64 as u64 => $len: $($cl),+);
+        bit_numbering_const!(i128 as u128 => $len: $($cl),+);
+    };
+}
+
+bit_numbering_const!(Split: u8, u16, u32, u64, u128);
+bit_numbering_const!(Signed: i8 as u8, i16 as u16, i32 as u32, i64 as u64, i128 as u128);
+
+/// Compile time check of partition field index
+struct FieldIndex<const COUNT: usize, const I: usize>;
+
+impl<const COUNT: usize, const I: usize> FieldIndex<COUNT, I> {
+    const ASSERT_INDEX_IN_BOUNDS: usize = COUNT - I - 1;
+}
+
+macro_rules! bit_numbering_with {
+    ($len:literal: $($cl:ident),+ $(,)?) => { paste!{
+        impl<TY: BitSource, $(const [<$cl N>]: usize,)+> [<P $len>]<TY, $([<$cl N>],)+> {
+            const ASSERT_INDEX_IN_BOUNDS: usize = TY::BITS - Self::SUM;
+
+            /// Number of source bits following the partition fields
+            pub const REMAINING_BITS: usize = TY::BITS - Self::SUM;
+
+            /// Field masks according to LSB 0 bit numbering
+            pub const MASKS_LSB: [u128; $len] = {
+                let mut masks = [0; $len];
+                let mut i = 0;
+                while i < $len {
+                    masks[i] = field_mask(Self::OFFSETS[i], Self::WIDTHS[i]);
+                    i += 1;
+                }
+                masks
+            };
+
+            /// Field masks according to MSB 0 bit numbering
+            pub const MASKS_MSB: [u128; $len] = {
+                let mut masks = [0; $len];
+                let mut i = 0;
+                while i < $len {
+                    let index = TY::BITS.saturating_sub(Self::OFFSETS[i] + Self::WIDTHS[i]);
+                    masks[i] = field_mask(index, Self::WIDTHS[i]);
+                    i += 1;
+                }
+                masks
+            };
+
+            #[doc=concat!("Replace field `I` of [P", $len, "] source according to LSB 0 bit numbering")]
+            ///
+            /// Bits of `value` that don't fit into field width are discarded.
+            #[doc=concat!("Use [P", $len, "::try_lsb_with] to reject such values.")]
+            pub fn lsb_with<const I: usize>(mut self, value: u128) -> Self {
+                #![allow(path_statements)]
+                FieldIndex::<$len, I>::ASSERT_INDEX_IN_BOUNDS;
+                Self::ASSERT_INDEX_IN_BOUNDS;
+
+                self.0.set_lsb_bits(Self::OFFSETS[I], Self::WIDTHS[I], value);
+                self
+            }
+
+            #[doc=concat!("Replace field `I` of [P", $len, "] source according to MSB 0 bit numbering")]
+            ///
+            /// Bits of `value` that don't fit into field width are discarded.
+            #[doc=concat!("Use [P", $len, "::try_msb_with] to reject such values.")]
+            pub fn msb_with<const I: usize>(mut self, value: u128) -> Self {
+                #![allow(path_statements)]
+                FieldIndex::<$len, I>::ASSERT_INDEX_IN_BOUNDS;
+                Self::ASSERT_INDEX_IN_BOUNDS;
+
+                self.0.set_msb_bits(Self::OFFSETS[I], Self::WIDTHS[I], value);
+                self
+            }
+
+            #[doc=concat!("Checked [P", $len, "::lsb_with] that returns `value` back if it doesn't fit into field width")]
+            pub fn try_lsb_with<const I: usize>(self, value: u128) -> Result<Self, u128> {
+                if shr128(value, Self::WIDTHS[I]) == 0 {
+                    Ok(self.lsb_with::<I>(value))
+                } else {
+                    Err(value)
+                }
+            }
+
+            #[doc=concat!("Checked [P", $len, "::msb_with] that returns `value` back if it doesn't fit into field width")]
+            pub fn try_msb_with<const I: usize>(self, value: u128) -> Result<Self, u128> {
+                if shr128(value, Self::WIDTHS[I]) == 0 {
+                    Ok(self.msb_with::<I>(value))
+                } else {
+                    Err(value)
+                }
+            }
+        }
+
+        /*
+        impl<TY, const AN: usize, const BN: usize, const CN: usize> P3<TY, AN, BN, CN> {
+            pub fn lsb_into_spanned<U: FromLsb<Self> + WithSpans<3>>(self) -> U::Output {
+                U::from_lsb(self).with_spans(Self::spans())
+            }
+        }
+
+        impl<TY, const AN: usize, const BN: usize, const CN: usize> Spanned<P3<TY, AN, BN, CN>> {
+            pub fn lsb_fields_into<U: FromLsb<P3<TY, AN, BN, CN>> + WithSpans<3>>(self) -> U::Output {
+                let Spanned { value, span } = self;
+                let spans = P3::<TY, AN, BN, CN>::spans()
+                    .map(|Range { start, end }| start + span.start..end + span.start);
+                U::from_lsb(value).with_spans(spans)
+            }
+        }
+        */
+        impl<TY, $(const [<$cl N>]: usize,)+> [<P $len>]<TY, $([<$cl N>],)+> {
+            /// Conversion according to LSB 0 bit numbering that pairs every field with its bits range
+            pub fn lsb_into_spanned<U: FromLsb<Self> + WithSpans<$len>>(self) -> U::Output {
+                U::from_lsb(self).with_spans(Self::spans())
+            }
+
+            /// Conversion according to MSB 0 bit numbering that pairs every field with its bits range
+            pub fn msb_into_spanned<U: FromMsb<Self> + WithSpans<$len>>(self) -> U::Output {
+                U::from_msb(self).with_spans(Self::spans())
+            }
+        }
+
+        impl<TY, $(const [<$cl N>]: usize,)+> Spanned<[<P $len>]<TY, $([<$cl N>],)+>> {
+            /// Converts nested partition according to LSB 0 bit numbering into fields with spans
+            /// shifted by partition span
+            pub fn lsb_fields_into<U>(self) -> U::Output
+            where
+                U: FromLsb<[<P $len>]<TY, $([<$cl N>],)+>> + WithSpans<$len>,
+            {
+                let Spanned { value, span } = self;
+                let spans = [<P $len>]::<TY, $([<$cl N>],)+>::spans()
+                    .map(|Range { start, end }| start + span.start..end + span.start);
+                U::from_lsb(value).with_spans(spans)
+            }
+
+            /// Converts nested partition according to MSB 0 bit numbering into fields with spans
+            /// shifted by partition span
+            pub fn msb_fields_into<U>(self) -> U::Output
+            where
+                U: FromMsb<[<P $len>]<TY, $([<$cl N>],)+>> + WithSpans<$len>,
+            {
+                let Spanned { value, span } = self;
+                let spans = [<P $len>]::<TY, $([<$cl N>],)+>::spans()
+                    .map(|Range { start, end }| start + span.start..end + span.start);
+                U::from_msb(value).with_spans(spans)
+            }
+        }
+
+        impl<TY: BitSource, $(const [<$cl N>]: usize,)+> [<P $len>]<TY, $([<$cl N>],)+> {
+            /// Writes source bits, field masks and values according to LSB 0 bit numbering
+            pub fn write_lsb_dump<W: core::fmt::Write>(&self, w: &mut W) -> core::fmt::Result {
+                layout::Layout(Self::WIDTHS.map(layout::Field::bits)).write_lsb_dump(w, &self.0)
+            }
+
+            /// Writes source bits, field masks and values according to MSB 0 bit numbering
+            pub fn write_msb_dump<W: core::fmt::Write>(&self, w: &mut W) -> core::fmt::Result {
+                layout::Layout(Self::WIDTHS.map(layout::Field::bits)).write_msb_dump(w, &self.0)
+            }
+        }
+    }};
 }
 
 
@@ -180,6 +1237,36 @@ impl<T, U: FromMsb<T>> MsbInto<U> for T {
     }
 }
 
+/// Fallible MSB 0 bit numbering data to value conversion
+///
+/// Rejects values that don't fit into resulting types, see [TryAsPrimitive].
+/// It is the reciprocal of [TryMsbInto].
+pub trait TryFromMsb<T: Sized>: Sized {
+    type Error;
+    const BITS: usize = size_of::<T>() * 8;
+    const MAX_BIT_INDEX: usize = Self::BITS - 1;
+    const ASSERT_INDEX_IN_BOUNDS: usize = Self::BITS - Self::MAX_BIT_INDEX;
+    fn try_from_msb(_: T) -> Result<Self, Self::Error>;
+}
+
+/// Fallible MSB 0 bit numbering data to value conversion that consumes the input bytes
+///
+/// The opposite of [TryFromMsb].
+/// One should avoid implementing [TryMsbInto] and implement [TryFromMsb] instead.
+pub trait TryMsbInto<T>: Sized {
+    type Error;
+    fn try_msb_into(self) -> Result<T, Self::Error>;
+}
+
+/// Implementing [TryFromMsb] automatically provides one with an implementation of [TryMsbInto]
+/// thanks to this blanket implementation.
+impl<T, U: TryFromMsb<T>> TryMsbInto<U> for T {
+    type Error = U::Error;
+    fn try_msb_into(self) -> Result<U, Self::Error> {
+        U::try_from_msb(self)
+    }
+}
+
 /**
 Split integer at some point according to MSB 0 bit numbering
 
@@ -189,10 +1276,34 @@ const U32: u32 = 0b1111_1111_0__101_1010_1100_0011_1000_0001;
 let (a,b) = msb_split::<_, 9>(U32);
 assert_eq!((0b1111_1111_0,0b101_1010_1100_0011_1000_0001 << 9), (a,b), "{a:b},{b:b}");
 ```
+
+Signed integers are split as their unsigned counterparts
+```rust
+# use heterob::bit_numbering::msb_split;
+let (a,b) = msb_split::<_, 3>(-1i8);
+assert_eq!((0b111, -1 << 3), (a,b));
+```
 */
 pub fn msb_split<T: Integral, const N: usize>(data: T) -> (T, T) {
-    let mask = T::MAX >> N;
-    ((data & !mask) >> (T::BITS as usize - N), (data & mask) << N)
+    let head = logical_shr(data, (T::BITS as usize).saturating_sub(N));
+    (head, shl(data, N))
+}
+
+/**
+Replace `N` bits of integer starting from bit `INDEX` according to MSB 0 bit numbering
+
+```rust
+# use heterob::bit_numbering::msb_set;
+let register: u16 = 0b1111_0000_0000_1111;
+let result = msb_set::<_, 4, 4>(register, 0b1010);
+assert_eq!(0b1111_1010_0000_1111, result);
+```
+*/
+pub fn msb_set<T: Integral, const INDEX: usize, const N: usize>(data: T, value: T) -> T {
+    #![allow(path_statements)]
+    Field::<T, INDEX, N>::ASSERT_INDEX_IN_BOUNDS;
+
+    set_bits(data, T::BITS as usize - INDEX - N, N, value)
 }
 
 
@@ -200,18 +1311,21 @@ pub fn msb_split<T: Integral, const N: usize>(data: T) -> (T, T) {
 // // Implemented in [bit_numbering_alphabet]
 // impl<TY, A,B,C, const AN: usize, const BN: usize, const CN: usize> FromLsb<P3<TY,AN,BN,CN>> for (A,B,C)
 // where
-//     TY: Integral + AsPrimitive<A> + AsPrimitive<B> + AsPrimitive<C>,
+//     TY: BitSource,
+//     A: FromBits<AN>,
+//     B: FromBits<BN>,
+//     C: FromBits<CN>,
 // {
-//     const BITS: usize = TY::BITS as usize;
+//     const BITS: usize = TY::BITS;
 //     const MAX_BIT_INDEX: usize = AN + BN + CN;
 //     fn from_lsb(P3(_data): P3<TY,AN,BN,CN>) -> Self {
 //         #![allow(path_statements)]
 //         <Self as FromLsb<P3<TY, AN,BN,CN>>>::ASSERT_INDEX_IN_BOUNDS;
 
-//         let (a, _data) = lsb_split::<_, AN>(_data);
-//         let (b, _data) = lsb_split::<_, BN>(_data);
-//         let (c, _data) = lsb_split::<_, CN>(_data);
-//         (a.as_primitive(), b.as_primitive(), c.as_primitive())
+//         let a = _data.lsb_bits(0, AN);
+//         let b = _data.lsb_bits(AN, BN);
+//         let c = _data.lsb_bits(AN + BN, CN);
+//         (A::from_bits(a), B::from_bits(b), C::from_bits(c))
 //     }
 // }
 
@@ -225,6 +1339,173 @@ pub fn msb_split<T: Integral, const N: usize>(data: T) -> (T, T) {
 //     }
 // }
 
+macro_rules! bit_numbering_hlist {
+    ($($sb:ident),+) => { paste!{ $(
+        // impl<TY> FromLsb<TY> for HNil
+        impl<TY> [<From $sb>]<TY> for HNil {
+            const MAX_BIT_INDEX: usize = 0;
+            fn [<from_ $sb:lower>](_: TY) -> Self {
+                HNil
+            }
+        }
+
+        /*
+        impl<TY, H, T, const HN: usize> FromLsb<TY> for HCons<H, HN, T>
+        where
+            TY: BitSource + Clone,
+            H: FromBits<HN>,
+            T: FromLsb<TY>,
+        {
+            const BITS: usize = TY::BITS;
+            const MAX_BIT_INDEX: usize = HN + <T as FromLsb<TY>>::MAX_BIT_INDEX;
+            const ASSERT_INDEX_IN_BOUNDS: usize = {
+                // Fields are read into u128
+                let _ = u128::BITS as usize - HN;
+                TY::BITS - Self::MAX_BIT_INDEX
+            };
+            fn from_lsb(data: TY) -> Self {
+                #![allow(path_statements)]
+                <Self as FromLsb<TY>>::ASSERT_INDEX_IN_BOUNDS;
+
+                let head = data.lsb_bits(0, HN);
+                HCons(H::from_bits(head), data.lsb_skip(HN).lsb_into())
+            }
+        }
+        */
+        impl<TY, H, T, const HN: usize> [<From $sb>]<TY> for HCons<H, HN, T>
+        where
+            TY: BitSource + Clone,
+            H: FromBits<HN>,
+            T: [<From $sb>]<TY>,
+        {
+            const BITS: usize = TY::BITS;
+            const MAX_BIT_INDEX: usize = HN + <T as [<From $sb>]<TY>>::MAX_BIT_INDEX;
+            const ASSERT_INDEX_IN_BOUNDS: usize = {
+                // Fields are read into u128
+                let _ = u128::BITS as usize - HN;
+                TY::BITS - Self::MAX_BIT_INDEX
+            };
+            fn [<from_ $sb:lower>](data: TY) -> Self {
+                #![allow(path_statements)]
+                <Self as [<From $sb>]<TY>>::ASSERT_INDEX_IN_BOUNDS;
+
+                let head = data.[<$sb:lower _bits>](0, HN);
+                HCons(H::from_bits(head), data.[<$sb:lower _skip>](HN).[<$sb:lower _into>]())
+            }
+        }
+    )+ }};
+}
+
+bit_numbering_hlist!(Lsb, Msb);
+
+bit_selection_alphabet!(1: A);
+bit_selection_alphabet!(2: A,B);
+bit_selection_alphabet!(3: A,B,C);
+bit_selection_alphabet!(4: A,B,C,D);
+bit_selection_alphabet!(5: A,B,C,D,E);
+bit_selection_alphabet!(6: A,B,C,D,E,F);
+bit_selection_alphabet!(7: A,B,C,D,E,F,G);
+bit_selection_alphabet!(8: A,B,C,D,E,F,G,H);
+bit_selection_alphabet!(9: A,B,C,D,E,F,G,H,I);
+bit_selection_alphabet!(10: A,B,C,D,E,F,G,H,I,J);
+bit_selection_alphabet!(11: A,B,C,D,E,F,G,H,I,J,K);
+bit_selection_alphabet!(12: A,B,C,D,E,F,G,H,I,J,K,L);
+bit_selection_alphabet!(13: A,B,C,D,E,F,G,H,I,J,K,L,M);
+bit_selection_alphabet!(14: A,B,C,D,E,F,G,H,I,J,K,L,M,N);
+bit_selection_alphabet!(15: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O);
+bit_selection_alphabet!(16: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P);
+bit_selection_alphabet!(17: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q);
+bit_selection_alphabet!(18: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R);
+bit_selection_alphabet!(19: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S);
+bit_selection_alphabet!(20: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T);
+bit_selection_alphabet!(21: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T,U);
+bit_selection_alphabet!(22: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T,U,V);
+bit_selection_alphabet!(23: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T,U,V,W);
+bit_selection_alphabet!(24: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T,U,V,W,X);
+bit_selection_alphabet!(25: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T,U,V,W,X,Y);
+bit_selection_alphabet!(26: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T,U,V,W,X,Y,Z);
+
+bit_numbering_with!(1: A);
+bit_numbering_with!(2: A,B);
+bit_numbering_with!(3: A,B,C);
+bit_numbering_with!(4: A,B,C,D);
+bit_numbering_with!(5: A,B,C,D,E);
+bit_numbering_with!(6: A,B,C,D,E,F);
+bit_numbering_with!(7: A,B,C,D,E,F,G);
+bit_numbering_with!(8: A,B,C,D,E,F,G,H);
+bit_numbering_with!(9: A,B,C,D,E,F,G,H,I);
+bit_numbering_with!(10: A,B,C,D,E,F,G,H,I,J);
+bit_numbering_with!(11: A,B,C,D,E,F,G,H,I,J,K);
+bit_numbering_with!(12: A,B,C,D,E,F,G,H,I,J,K,L);
+bit_numbering_with!(13: A,B,C,D,E,F,G,H,I,J,K,L,M);
+bit_numbering_with!(14: A,B,C,D,E,F,G,H,I,J,K,L,M,N);
+bit_numbering_with!(15: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O);
+bit_numbering_with!(16: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P);
+bit_numbering_with!(17: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q);
+bit_numbering_with!(18: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R);
+bit_numbering_with!(19: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S);
+bit_numbering_with!(20: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T);
+bit_numbering_with!(21: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T,U);
+bit_numbering_with!(22: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T,U,V);
+bit_numbering_with!(23: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T,U,V,W);
+bit_numbering_with!(24: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T,U,V,W,X);
+bit_numbering_with!(25: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T,U,V,W,X,Y);
+bit_numbering_with!(26: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T,U,V,W,X,Y,Z);
+
+bit_numbering_seq!(1: A);
+bit_numbering_seq!(2: A,B);
+bit_numbering_seq!(3: A,B,C);
+bit_numbering_seq!(4: A,B,C,D);
+bit_numbering_seq!(5: A,B,C,D,E);
+bit_numbering_seq!(6: A,B,C,D,E,F);
+bit_numbering_seq!(7: A,B,C,D,E,F,G);
+bit_numbering_seq!(8: A,B,C,D,E,F,G,H);
+bit_numbering_seq!(9: A,B,C,D,E,F,G,H,I);
+bit_numbering_seq!(10: A,B,C,D,E,F,G,H,I,J);
+bit_numbering_seq!(11: A,B,C,D,E,F,G,H,I,J,K);
+bit_numbering_seq!(12: A,B,C,D,E,F,G,H,I,J,K,L);
+bit_numbering_seq!(13: A,B,C,D,E,F,G,H,I,J,K,L,M);
+bit_numbering_seq!(14: A,B,C,D,E,F,G,H,I,J,K,L,M,N);
+bit_numbering_seq!(15: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O);
+bit_numbering_seq!(16: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P);
+bit_numbering_seq!(17: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q);
+bit_numbering_seq!(18: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R);
+bit_numbering_seq!(19: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S);
+bit_numbering_seq!(20: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T);
+bit_numbering_seq!(21: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T,U);
+bit_numbering_seq!(22: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T,U,V);
+bit_numbering_seq!(23: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T,U,V,W);
+bit_numbering_seq!(24: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T,U,V,W,X);
+bit_numbering_seq!(25: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T,U,V,W,X,Y);
+bit_numbering_seq!(26: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T,U,V,W,X,Y,Z);
+
+bit_numbering_const!(1: A);
+bit_numbering_const!(2: A,B);
+bit_numbering_const!(3: A,B,C);
+bit_numbering_const!(4: A,B,C,D);
+bit_numbering_const!(5: A,B,C,D,E);
+bit_numbering_const!(6: A,B,C,D,E,F);
+bit_numbering_const!(7: A,B,C,D,E,F,G);
+bit_numbering_const!(8: A,B,C,D,E,F,G,H);
+bit_numbering_const!(9: A,B,C,D,E,F,G,H,I);
+bit_numbering_const!(10: A,B,C,D,E,F,G,H,I,J);
+bit_numbering_const!(11: A,B,C,D,E,F,G,H,I,J,K);
+bit_numbering_const!(12: A,B,C,D,E,F,G,H,I,J,K,L);
+bit_numbering_const!(13: A,B,C,D,E,F,G,H,I,J,K,L,M);
+bit_numbering_const!(14: A,B,C,D,E,F,G,H,I,J,K,L,M,N);
+bit_numbering_const!(15: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O);
+bit_numbering_const!(16: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P);
+bit_numbering_const!(17: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q);
+bit_numbering_const!(18: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R);
+bit_numbering_const!(19: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S);
+bit_numbering_const!(20: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T);
+bit_numbering_const!(21: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T,U);
+bit_numbering_const!(22: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T,U,V);
+bit_numbering_const!(23: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T,U,V,W);
+bit_numbering_const!(24: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T,U,V,W,X);
+bit_numbering_const!(25: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T,U,V,W,X,Y);
+bit_numbering_const!(26: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T,U,V,W,X,Y,Z);
+
 bit_numbering_alphabet!(1: A);
 bit_numbering_alphabet!(2: A,B);
 bit_numbering_alphabet!(3: A,B,C);
@@ -253,6 +1534,7 @@ bit_numbering_alphabet!(25: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T,U,V,W,X,Y);
 bit_numbering_alphabet!(26: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T,U,V,W,X,Y,Z);
 
 #[cfg(test)]
+#[allow(clippy::unusual_byte_groupings)]
 mod tests {
     use super::*;
 
@@ -280,6 +1562,479 @@ mod tests {
         );
     }
 
+    /// Collects list of bools into integer bits, LSB first
+    trait Collect {
+        fn bits(self) -> u32;
+    }
+    impl Collect for HNil {
+        fn bits(self) -> u32 {
+            0
+        }
+    }
+    impl<T: Collect> Collect for HCons<bool, 1, T> {
+        fn bits(self) -> u32 {
+            self.0 as u32 | self.1.bits() << 1
+        }
+    }
+
+    #[test]
+    fn hlist_longer_than_tuple_wrappers() {
+        type B4<T> = HCons<bool, 1, HCons<bool, 1, HCons<bool, 1, HCons<bool, 1, T>>>>;
+        type B32 = B4<B4<B4<B4<B4<B4<B4<B4<HNil>>>>>>>>;
+
+        let result: B32 = U32.lsb_into();
+        assert_eq!(U32, result.bits(), "LSB 0");
+
+        let result: B32 = U32.msb_into();
+        assert_eq!(U32.reverse_bits(), result.bits(), "MSB 0");
+    }
+
+    #[test]
+    fn hlist_into_mixed_types() {
+        type L = HCons<u16, 15, HCons<bool, 1, HCons<u8, 2, HNil>>>;
+
+        let HCons(a, HCons(b, HCons(c, HNil))): L = U32.lsb_into();
+        assert_eq!((0b100_0011_1000_0001, true, 0b10), (a, b, c), "LSB 0");
+
+        let HCons(a, HCons(b, HCons(c, HNil))): L = U32.msb_into();
+        assert_eq!((0b1111_1111_0101_101, false, 0b11), (a, b, c), "MSB 0");
+
+        let (a, b, c) = L::from_lsb(U32.to_le_bytes()).into_tuple();
+        assert_eq!((0b100_0011_1000_0001, true, 0b10), (a, b, c), "LSB 0 array");
+
+        let (a, b, c) = L::from_msb(U32.to_be_bytes()).into_tuple();
+        assert_eq!((0b1111_1111_0101_101, false, 0b11), (a, b, c), "MSB 0 array");
+    }
+
+    #[test]
+    fn from_bits_gets_field_width() {
+        #[derive(Debug, PartialEq, Eq)]
+        struct Width(usize);
+        impl<const W: usize> FromBits<W> for Width {
+            fn from_bits(_: u128) -> Self {
+                Self(W)
+            }
+        }
+
+        let result = P3::<_, 15, 1, 2>(U32).lsb_into();
+        assert_eq!((Width(15), Width(1), Width(2)), result);
+
+        let result: HCons<Width, 3, HCons<u8, 5, HNil>> = U32.msb_into();
+        assert_eq!(HCons(Width(3), HCons(0b1_1111, HNil)), result);
+
+        let result = U32.select::<(Bits<4, 7>, Gather<(Bits<0, 2>, Bits<8, 3>), 5>), _>();
+        assert_eq!((Width(7), Width(5)), result, "selection");
+    }
+
+    #[test]
+    fn try_lsb_into_tuple() {
+        let result: Result<(u16, bool, u8, ()), _> = P4::<_, 15, 1, 2, 14>(U32).try_lsb_into();
+        assert_eq!(Ok((0b100_0011_1000_0001, true, 0b10, ())), result);
+
+        let result: Result<(u8, bool, u8), _> = P3::<_, 15, 1, 2>(U32).try_lsb_into();
+        assert_eq!(Err(T3Error::A(0b100_0011_1000_0001)), result);
+
+        let result: Result<(u16, bool, bool), _> = P3::<_, 15, 1, 2>(U32).try_lsb_into();
+        assert_eq!(Err(2), result.map_err(|e| e.index()));
+    }
+
+    #[test]
+    fn try_msb_into_tuple() {
+        let result: Result<(u16, bool, U8<u8>), _> = P3::<_, 15, 1, 2>(U32).try_msb_into();
+        assert_eq!(Ok((0b1111_1111_0101_101, false, U8(0b11))), result);
+
+        let result: Result<(u8, bool, u8), _> = P3::<_, 15, 1, 2>(U32).try_msb_into();
+        assert_eq!(Err(0), result.map_err(|e| e.index()));
+    }
+
+    #[test]
+    fn zero_and_full_width_split() {
+        assert_eq!((0, 0xA5), lsb_split::<_, 0>(0xA5u8));
+        assert_eq!((0xA5, 0), lsb_split::<_, 8>(0xA5u8));
+        assert_eq!((0xA5, 0), lsb_split::<_, 9>(0xA5u8));
+        assert_eq!((0, 0xA5), msb_split::<_, 0>(0xA5u8));
+        assert_eq!((0xA5, 0), msb_split::<_, 8>(0xA5u8));
+        assert_eq!((0xA5, 0), msb_split::<_, 9>(0xA5u8));
+        assert_eq!((-1, 0), lsb_split::<_, 8>(-1i8));
+        assert_eq!((-1, 0), msb_split::<_, 8>(-1i8));
+    }
+
+    #[test]
+    fn zero_and_full_width_fields() {
+        const U128: u128 = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210;
+
+        assert_eq!((0xA5u8,), P1::<u8, 8>(0xA5).lsb_into());
+        assert_eq!((0xA5u8,), P1::<u8, 8>(0xA5).msb_into());
+        assert_eq!((u64::MAX,), P1::<u64, 64>(u64::MAX).lsb_into());
+        assert_eq!(((), U32), P2::<u32, 0, 32>(U32).lsb_into());
+        assert_eq!(((), U32), P2::<u32, 0, 32>(U32).msb_into());
+        assert_eq!((U32, ()), P2::<u32, 32, 0>(U32).lsb_into());
+        assert_eq!((U32, ()), P2::<u32, 32, 0>(U32).msb_into());
+
+        let (a, (), c): (u64, (), u64) = P3::<u128, 64, 0, 64>(U128).lsb_into();
+        assert_eq!((0xFEDC_BA98_7654_3210, 0x0123_4567_89AB_CDEF), (a, c), "LSB 0");
+        let (a, (), c): (u64, (), u64) = P3::<u128, 64, 0, 64>(U128).msb_into();
+        assert_eq!((0x0123_4567_89AB_CDEF, 0xFEDC_BA98_7654_3210), (a, c), "MSB 0");
+
+        let (a, b, c): (u8, u8, u8) = P3::<u8, 0, 8, 0>(0xA5).msb_into();
+        assert_eq!((0, 0xA5, 0), (a, b, c), "zero width field is 0");
+
+        let result: Result<(u8,), _> = P1::<i8, 8>(-1).try_msb_into();
+        assert_eq!(Ok((0xFF,)), result, "checked full width");
+
+        let result: HCons<u8, 0, HCons<u32, 32, HCons<u8, 0, HNil>>> = U32.lsb_into();
+        assert_eq!(HCons(0, HCons(U32, HCons(0, HNil))), result, "list");
+    }
+
+    #[test]
+    fn partition_of_byte_array() {
+        const BYTES: [u8; 4] = U32.to_le_bytes();
+        let result: (u16, bool, u8, ()) = P4::<_, 15, 1, 2, 14>(BYTES).lsb_into();
+        let sample: (u16, bool, u8, ()) = P4::<_, 15, 1, 2, 14>(U32).lsb_into();
+        assert_eq!(sample, result, "LSB 0 as little endian");
+
+        const BE_BYTES: [u8; 4] = U32.to_be_bytes();
+        let result: (u16, bool, u8) = P3::<_, 15, 1, 2>(BE_BYTES).msb_into();
+        let sample: (u16, bool, u8) = P3::<_, 15, 1, 2>(U32).msb_into();
+        assert_eq!(sample, result, "MSB 0 as big endian");
+
+        let result: Result<(u16, u8), _> = P2::<_, 12, 20>(BE_BYTES).try_msb_into();
+        assert_eq!(Err(1), result.map_err(|e| e.index()), "checked");
+    }
+
+    #[test]
+    fn partition_of_word_array() {
+        // 256 bits feature bitmap
+        let mut words = [0u32; 8];
+        words[0] = 0b1;
+        words[4] = 0x8000_0000;
+        words[7] = 0xFFFF_0000;
+        let (first, (), (), middle, (), (), last): (bool, (), (), u64, (), (), u16) =
+            P7::<_, 1, 128, 30, 2, 64, 15, 16>(words).lsb_into();
+        assert_eq!((true, 0b01, 0xFFFF), (first, middle, last), "LSB 0");
+
+        let (first, (), last): (u32, (), u128) = P3::<_, 32, 96, 128>(words).msb_into();
+        assert_eq!((1, 0x8000_0000_0000_0000_0000_0000_FFFF_0000), (first, last), "MSB 0");
+    }
+
+    #[test]
+    fn partition_of_ordered_word_array() {
+        let words = [0x0123u16, 0x4567, 0x89AB];
+
+        let lsb: (u16, u32) = P2::<_, 16, 32>(Lsb0(words)).lsb_into();
+        let plain: (u16, u32) = P2::<_, 16, 32>(words).lsb_into();
+        assert_eq!(plain, lsb, "Lsb0 LSB 0");
+        let (a, b, c): (u16, u16, u16) = P3::<_, 16, 16, 16>(Lsb0(words)).msb_into();
+        assert_eq!((0x89AB, 0x4567, 0x0123), (a, b, c), "Lsb0 MSB 0");
+
+        let msb: (u16, u32) = P2::<_, 16, 32>(Msb0(words)).msb_into();
+        let plain: (u16, u32) = P2::<_, 16, 32>(words).msb_into();
+        assert_eq!(plain, msb, "Msb0 MSB 0");
+        let (a, b, c): (u16, u16, u16) = P3::<_, 16, 16, 16>(Msb0(words)).lsb_into();
+        assert_eq!((0x89AB, 0x4567, 0x0123), (a, b, c), "Msb0 LSB 0");
+
+        let (a, b): (u8, u16) = P2::<_, 8, 16>(Lsb0(words)).msb_into();
+        assert_eq!((0x89, 0xAB45), (a, b), "Lsb0 MSB 0 across words");
+        let (a, b): (u8, u16) = P2::<_, 8, 16>(Msb0(words)).lsb_into();
+        assert_eq!((0xAB, 0x6789), (a, b), "Msb0 LSB 0 across words");
+    }
+
+    #[test]
+    fn ordered_word_array_with_field() {
+        let P2(Lsb0(words)) = P2::<_, 8, 40>(Lsb0([0u16; 3])).msb_with::<0>(0xA5);
+        assert_eq!([0, 0, 0xA500], words, "Lsb0");
+        let P2(Msb0(words)) = P2::<_, 8, 40>(Msb0([0u16; 3])).lsb_with::<0>(0xA5);
+        assert_eq!([0, 0, 0x00A5], words, "Msb0");
+
+        let BitSeq { head: (a,), tail, .. } = P1::<_, 8>(Lsb0([0x0123u16, 0x4567, 0x89AB])).msb_into();
+        assert_eq!(0x89u8, a);
+        assert_eq!(Lsb0([0x2300, 0x6701, 0xAB45]), tail, "Lsb0 MSB 0 tail");
+    }
+
+    #[test]
+    fn select_bits() {
+        let result: u8 = U32.select::<Bits<28, 4>, _>();
+        assert_eq!(0b1111, result);
+
+        let (a, b, c): (u16, bool, u8) = U32.select::<(Bits<0, 15>, Bits<15, 1>, Bits<16, 2>), _>();
+        let sample: (u16, bool, u8) = P3::<_, 15, 1, 2>(U32).lsb_into();
+        assert_eq!(sample, (a, b, c), "same as partition");
+
+        let (high, low): (u8, u8) = U32.select::<(Bits<24, 8>, Bits<0, 8>), _>();
+        assert_eq!((0b1111_1111, 0b1000_0001), (high, low), "out of order");
+
+        let bytes = U32.to_le_bytes();
+        let result: u8 = bytes.select::<Bits<28, 4>, _>();
+        assert_eq!(0b1111, result, "array source");
+    }
+
+    #[test]
+    fn gather_bits() {
+        let result: u16 = U32.select::<Gather<(Bits<0, 4>, Bits<28, 4>), 8>, _>();
+        assert_eq!(0b1111_0001, result);
+
+        let result: u32 = U32.select::<Gather<(Bits<0, 16>, Bits<16, 16>), 32>, _>();
+        assert_eq!(U32, result, "contiguous ranges");
+
+        let (a, b): (u8, u8) = U32.select::<(Gather<(Bits<0, 1>, Bits<31, 1>), 2>, Bits<1, 1>), _>();
+        assert_eq!((0b11, 0), (a, b), "tuple of selectors");
+    }
+
+    #[test]
+    fn set_bits() {
+        assert_eq!(0b1111_1111_0101_1010_1100_0011_1010_0001, lsb_set::<_, 4, 4>(U32, 0b1010));
+        assert_eq!(0b1111_1010_0101_1010_1100_0011_1000_0001, msb_set::<_, 4, 4>(U32, 0b1010));
+        assert_eq!(0b1111_1111_0101_1010_1100_0011_1000_0000, lsb_set::<_, 0, 1>(U32, 0b10), "value is masked");
+        assert_eq!(0, lsb_set::<_, 0, 32>(U32, 0), "full width");
+        assert_eq!(U32, msb_set::<_, 32, 0>(U32, 0), "zero width");
+        assert_eq!(-1, lsb_set::<_, 7, 1>(i8::MAX, 1), "signed");
+    }
+
+    #[test]
+    fn partition_with_field() {
+        let P4(result) = P4::<u32, 3, 5, 8, 16>(U32).lsb_with::<2>(0xA5);
+        assert_eq!(0b1111_1111_0101_1010_1010_0101_1000_0001, result);
+
+        let P4(result) = P4::<u32, 3, 5, 8, 16>(U32).lsb_with::<0>(0);
+        assert_eq!(U32 & !0b111, result);
+
+        let P4(result) = P4::<u32, 3, 5, 8, 16>(U32).lsb_with::<0>(0b1010);
+        assert_eq!(U32 & !0b111 | 0b010, result, "value is masked");
+
+        let P4(result) = P4::<u32, 3, 5, 8, 16>(U32).msb_with::<3>(0xFFFF);
+        assert_eq!(0b1111_1111_0101_1010_1111_1111_1111_1111, result, "MSB 0");
+
+        let partition = P3::<u32, 15, 1, 2>(U32).msb_with::<1>(1);
+        let (_, b, _): (u16, bool, u8) = partition.msb_into();
+        assert!(b, "MSB 0 round trip");
+    }
+
+    #[test]
+    fn partition_try_with_field() {
+        let partition = || P4::<u32, 3, 5, 8, 16>(U32);
+        assert_eq!(Ok(partition().lsb_with::<2>(0xA5)), partition().try_lsb_with::<2>(0xA5));
+        assert_eq!(Err(0x1A5), partition().try_lsb_with::<2>(0x1A5));
+        assert_eq!(Ok(partition().msb_with::<3>(0xFFFF)), partition().try_msb_with::<3>(0xFFFF));
+        assert_eq!(Err(0x1_0000), partition().try_msb_with::<3>(0x1_0000));
+
+        assert_eq!(Err(0x1F), P2::<i8, 4, 4>(0).try_lsb_with::<0>(0x1F), "signed");
+        assert_eq!(Ok(P2(i8::MIN)), P2::<i8, 4, 4>(0).try_msb_with::<0>(0b1000), "signed");
+
+        let P2(result) = P2::<[u8; 2], 4, 12>([0; 2]).msb_with::<1>(0xABC);
+        assert_eq!([0x0A, 0xBC], result, "MSB 0 array");
+        let P2(result) = P2::<[u8; 2], 4, 12>([0; 2]).lsb_with::<1>(0xABC);
+        assert_eq!([0xC0, 0xAB], result, "LSB 0 array");
+    }
+
+    #[test]
+    fn bit_seq() {
+        let BitSeq { head, tail, bits } = P2::<_, 15, 1>(U32).lsb_into();
+        let _: (u16, bool) = head;
+        assert_eq!((0b100_0011_1000_0001, true), head, "LSB 0 head");
+        assert_eq!((0b1111_1111_0101_1010, 16), (tail, bits), "LSB 0 tail");
+
+        let BitSeq { head, tail, bits } = P2::<_, 15, 1>(U32).msb_into();
+        let _: (u16, bool) = head;
+        assert_eq!((0b1111_1111_0101_101, false), head, "MSB 0 head");
+        assert_eq!((0b1100_0011_1000_0001 << 16, 16), (tail, bits), "MSB 0 tail");
+
+        let BitSeq { head: ((),), tail, bits } = P1::<_, 32>(U32).lsb_into();
+        assert_eq!((0, 0), (tail, bits), "no remaining bits");
+        assert_eq!(16, P2::<u32, 15, 1>::REMAINING_BITS, "same as partition const");
+
+        let BitSeq { head: (a, b), tail, bits } = P2::<_, 4, 4>(-1i8).lsb_into();
+        let _: (u8, u8) = (a, b);
+        assert_eq!((0b1111, 0b1111, 0, 0), (a, b, tail, bits), "signed");
+
+        let BitSeq { head: (a,), tail, bits } = P1::<_, 12>(U32.to_le_bytes()).lsb_into();
+        let _: u16 = a;
+        assert_eq!((0b0011_1000_0001, (U32 >> 12).to_le_bytes(), 20), (a, tail, bits), "LSB 0 array");
+
+        let BitSeq { head: (a,), tail, bits } = P1::<_, 12>(U32.to_be_bytes()).msb_into();
+        let _: u16 = a;
+        assert_eq!((0b1111_1111_0101, (U32 << 12).to_be_bytes(), 20), (a, tail, bits), "MSB 0 array");
+    }
+
+    #[test]
+    fn skip_bits() {
+        let words = [U32, !U32, U32];
+        for n in [0, 1, 31, 32, 33, 95, 96, 100] {
+            let wide = (U32 as u128) | ((!U32 as u128) << 32) | ((U32 as u128) << 64);
+            let tail = words.lsb_skip(n);
+            assert_eq!(wide.checked_shr(n as u32).unwrap_or(0), tail.lsb_bits(0, 96), "LSB 0 {n}");
+
+            let wide = ((U32 as u128) << 64) | ((!U32 as u128) << 32) | (U32 as u128);
+            let tail = words.msb_skip(n);
+            let sample = wide.checked_shl(n as u32).unwrap_or(0) & ((1 << 96) - 1);
+            assert_eq!(sample, tail.msb_bits(0, 96), "MSB 0 {n}");
+        }
+    }
+
+    #[test]
+    fn spanned_bits() {
+        let (a, b, c) =
+            P3::<_, 7, 1, 8>(0b1111_0000_1100_1010u16).lsb_into_spanned::<(u8, bool, u8)>();
+        assert_eq!(Spanned { value: 0b100_1010, span: 0..7 }, a);
+        assert_eq!(Spanned { value: true, span: 7..8 }, b);
+        assert_eq!(Spanned { value: 0xF0, span: 8..16 }, c);
+
+        let (a, b) = P2::<_, 4, 12>(0xABCDu16).msb_into_spanned::<(u8, u16)>();
+        assert_eq!((0xA, 0..4), (a.value, a.span));
+        assert_eq!((0xBCD, 4..16), (b.value, b.span));
+    }
+
+    #[test]
+    fn spanned_bits_nested_in_bytes() {
+        use crate::endianness::Le;
+        let data = [0x00, 0b1010_0101, 0x22];
+        let Seq { head: Le((_, flags, _)), .. } = P3::<_, 1, 1, 1>(&data[..])
+            .try_into_spanned::<Le<(u8, u8, u8)>>()
+            .unwrap();
+        assert_eq!(1..2, flags.span);
+
+        let (a, b, c) = flags
+            .map(P3::<_, 1, 3, 4>)
+            .in_bits()
+            .lsb_fields_into::<(bool, u8, u8)>();
+        assert_eq!((true, 8..9), (a.value, a.span));
+        assert_eq!((0b010, 9..12), (b.value, b.span));
+        assert_eq!((0b1010, 12..16), (c.value, c.span));
+
+        let (a, b) = Spanned { value: P2::<_, 2, 6>(0b1100_0011u8), span: 24..32 }
+            .msb_fields_into::<(u8, u8)>();
+        assert_eq!((0b11, 24..26), (a.value, a.span), "MSB bits span");
+        assert_eq!((0b00_0011, 26..32), (b.value, b.span), "MSB bits span");
+    }
+
+    #[test]
+    fn partition_masks() {
+        type P = P4<u32, 3, 5, 8, 16>;
+        assert_eq!([0b111, 0b1111_1000, 0xFF00, 0xFFFF_0000], P::MASKS_LSB);
+        assert_eq!([0xE000_0000, 0x1F00_0000, 0xFF_0000, 0xFFFF], P::MASKS_MSB);
+        assert_eq!([u128::MAX, 0], P2::<u128, 128, 0>::MASKS_LSB, "full width");
+        assert_eq!([0, u128::MAX], P2::<u128, 0, 128>::MASKS_MSB, "full width");
+
+        let (_, _, field, _): (u8, u8, u8, u16) = P4::<_, 3, 5, 8, 16>(U32).lsb_into();
+        assert_eq!(field as u128, (U32 as u128 & P::MASKS_LSB[2]) >> P::OFFSETS[2]);
+    }
+
+    #[test]
+    fn const_fn_split() {
+        const LSB: (u32, u32) = lsb_split_u32::<9>(U32);
+        assert_eq!(lsb_split::<_, 9>(U32), LSB);
+        const MSB: (u32, u32) = msb_split_u32::<9>(U32);
+        assert_eq!(msb_split::<_, 9>(U32), MSB);
+        assert_eq!((0, 0xA5), lsb_split_u8::<0>(0xA5));
+        assert_eq!((0xA5, 0), lsb_split_u8::<8>(0xA5));
+        assert_eq!((0, 0xA5), msb_split_u8::<0>(0xA5));
+        assert_eq!((0xA5, 0), msb_split_u8::<8>(0xA5));
+    }
+
+    #[test]
+    fn const_fn_fields() {
+        const LSB: [u32; 4] = P4::<u32, 15, 1, 2, 14>(U32).lsb_fields();
+        let (a, b, c, d): (u32, u32, u32, u32) = P4::<_, 15, 1, 2, 14>(U32).lsb_into();
+        assert_eq!([a, b, c, d], LSB);
+
+        const MSB: [u32; 4] = P4::<u32, 15, 1, 2, 14>(U32).msb_fields();
+        let (a, b, c, d): (u32, u32, u32, u32) = P4::<_, 15, 1, 2, 14>(U32).msb_into();
+        assert_eq!([a, b, c, d], MSB);
+
+        assert_eq!([0, u128::MAX], P2::<u128, 0, 128>(u128::MAX).lsb_fields(), "full width");
+    }
+
+    #[test]
+    fn const_fn_signed() {
+        const LSB: (i8, i8) = lsb_split_i8::<4>(-2);
+        assert_eq!(lsb_split::<_, 4>(-2i8), LSB);
+        const MSB: (i32, i32) = msb_split_i32::<1>(-1);
+        assert_eq!(msb_split::<_, 1>(-1i32), MSB);
+
+        const HDR: (i16, u32) = {
+            let [kind, length] = P2::<i64, 16, 32>(-2).msb_fields();
+            (kind as i16, length as u32)
+        };
+        let (kind, length): (u16, u32) = P2::<_, 16, 32>(-2i64).msb_into();
+        assert_eq!((kind as i16, length), HDR);
+        assert_eq!((-1, u32::MAX), HDR);
+
+        const TABLE: [i8; 3] = P3::<i8, 2, 3, 3>(-128).lsb_fields();
+        let (a, b, c): (u8, u8, u8) = P3::<_, 2, 3, 3>(-128i8).lsb_into();
+        assert_eq!([a as i8, b as i8, c as i8], TABLE);
+        assert_eq!([0, 0, 0b100], TABLE);
+    }
+
+    #[test]
+    fn signed_split() {
+        assert_eq!((0b1110, 0b1111), lsb_split::<_, 4>(-2i8));
+        assert_eq!((0b1111, -2 << 4), msb_split::<_, 4>(-2i8));
+        assert_eq!((1, 0x7FFF_FFFF), lsb_split::<_, 1>(-1i32));
+        assert_eq!((1, -2), msb_split::<_, 1>(-1i32));
+    }
+
+    #[test]
+    fn signed_partition_as_unsigned() {
+        const I32: i32 = -0x55AA_F00F;
+
+        #[derive(Debug, PartialEq, Eq)]
+        struct Field<const W: usize>(u128);
+        impl<const W: usize> FromBits<W> for Field<W> {
+            fn from_bits(bits: u128) -> Self {
+                Self(bits)
+            }
+        }
+
+        // Fields are collected into arrays, because long tuples don't implement PartialEq
+        macro_rules! assert_every_arity {
+            ($($p:ident: $($f:ident $w:literal),+;)+) => { $(
+                let ($(Field::<$w>($f),)+) = $p::<i32, $($w),+>(I32).lsb_into();
+                let signed = [$($f),+];
+                let ($(Field::<$w>($f),)+) = $p::<u32, $($w),+>(I32 as u32).lsb_into();
+                assert_eq!([$($f),+], signed, "{} LSB 0", stringify!($p));
+
+                let ($(Field::<$w>($f),)+) = $p::<i32, $($w),+>(I32).msb_into();
+                let signed = [$($f),+];
+                let ($(Field::<$w>($f),)+) = $p::<u32, $($w),+>(I32 as u32).msb_into();
+                assert_eq!([$($f),+], signed, "{} MSB 0", stringify!($p));
+            )+ };
+        }
+
+        assert_every_arity! {
+            P1: a 32;
+            P2: a 31, b 1;
+            P3: a 30, b 1, c 1;
+            P4: a 29, b 1, c 1, d 1;
+            P5: a 28, b 1, c 1, d 1, e 1;
+            P6: a 27, b 1, c 1, d 1, e 1, f 1;
+            P7: a 26, b 1, c 1, d 1, e 1, f 1, g 1;
+            P8: a 25, b 1, c 1, d 1, e 1, f 1, g 1, h 1;
+            P9: a 24, b 1, c 1, d 1, e 1, f 1, g 1, h 1, i 1;
+            P10: a 23, b 1, c 1, d 1, e 1, f 1, g 1, h 1, i 1, j 1;
+            P11: a 22, b 1, c 1, d 1, e 1, f 1, g 1, h 1, i 1, j 1, k 1;
+            P12: a 21, b 1, c 1, d 1, e 1, f 1, g 1, h 1, i 1, j 1, k 1, l 1;
+            P13: a 20, b 1, c 1, d 1, e 1, f 1, g 1, h 1, i 1, j 1, k 1, l 1, m 1;
+            P14: a 19, b 1, c 1, d 1, e 1, f 1, g 1, h 1, i 1, j 1, k 1, l 1, m 1, n 1;
+            P15: a 18, b 1, c 1, d 1, e 1, f 1, g 1, h 1, i 1, j 1, k 1, l 1, m 1, n 1, o 1;
+            P16: a 17, b 1, c 1, d 1, e 1, f 1, g 1, h 1, i 1, j 1, k 1, l 1, m 1, n 1, o 1, p 1;
+            P17: a 16, b 1, c 1, d 1, e 1, f 1, g 1, h 1, i 1, j 1, k 1, l 1, m 1, n 1, o 1, p 1, q 1;
+            P18: a 15, b 1, c 1, d 1, e 1, f 1, g 1, h 1, i 1, j 1, k 1, l 1, m 1, n 1, o 1, p 1, q 1, r 1;
+            P19: a 14, b 1, c 1, d 1, e 1, f 1, g 1, h 1, i 1, j 1, k 1, l 1, m 1, n 1, o 1, p 1, q 1, r 1, s 1;
+            P20: a 13, b 1, c 1, d 1, e 1, f 1, g 1, h 1, i 1, j 1, k 1, l 1, m 1, n 1, o 1, p 1, q 1, r 1, s 1, t 1;
+            P21: a 12, b 1, c 1, d 1, e 1, f 1, g 1, h 1, i 1, j 1, k 1, l 1, m 1, n 1, o 1, p 1, q 1, r 1, s 1, t 1, u 1;
+            P22: a 11, b 1, c 1, d 1, e 1, f 1, g 1, h 1, i 1, j 1, k 1, l 1, m 1, n 1, o 1, p 1, q 1, r 1, s 1, t 1, u 1, v 1;
+            P23: a 10, b 1, c 1, d 1, e 1, f 1, g 1, h 1, i 1, j 1, k 1, l 1, m 1, n 1, o 1, p 1, q 1, r 1, s 1, t 1, u 1, v 1, w 1;
+            P24: a 9, b 1, c 1, d 1, e 1, f 1, g 1, h 1, i 1, j 1, k 1, l 1, m 1, n 1, o 1, p 1, q 1, r 1, s 1, t 1, u 1, v 1, w 1, x 1;
+            P25: a 8, b 1, c 1, d 1, e 1, f 1, g 1, h 1, i 1, j 1, k 1, l 1, m 1, n 1, o 1, p 1, q 1, r 1, s 1, t 1, u 1, v 1, w 1, x 1, y 1;
+            P26: a 7, b 1, c 1, d 1, e 1, f 1, g 1, h 1, i 1, j 1, k 1, l 1, m 1, n 1, o 1, p 1, q 1, r 1, s 1, t 1, u 1, v 1, w 1, x 1, y 1, z 1;
+        }
+
+        let (a, b, c): (u32, u8, bool) = P3::<i32, 24, 7, 1>(I32).lsb_into();
+        assert_eq!((I32 as u32 & 0xFF_FFFF, 0x2A, true), (a, b, c));
+
+        let result: Result<(u32, u8, bool), _> = P3::<i32, 24, 7, 1>(I32).try_lsb_into();
+        assert_eq!(Ok((a, b, c)), result, "checked");
+    }
+
     #[test]
     fn struct_lsb_into_tuple() {
         let Lsb((a, b, c, ())) = P4::<_, 15, 1, 2, 14>(U32).into();
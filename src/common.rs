@@ -14,6 +14,14 @@ Implementations:
 
   `[T;N]` -> `[T;AN],[T;BN],[T;CN]` where `AN + BN + CN = N`
 
+- [splits one array reference to multiple array references](struct.T3.html#impl-From%3C%26%27a%20%5BT%3B%20N%5D%3E)
+
+  `&[T;N]` -> `&[T;AN],&[T;BN],&[T;CN]` where `AN + BN + CN = N` without copying
+
+- [splits one mutable array reference to multiple disjoint mutable array references](struct.T3.html#impl-From%3C%26%27a%20mut%20%5BT%3B%20N%5D%3E)
+
+  `&mut [T;N]` -> `&mut [T;AN],&mut [T;BN],&mut [T;CN]` where `AN + BN + CN = N`
+
 - [helps transforms from native tuple](struct.T3.html#impl-From%3C(A0%2C%20B0%2C%20C0)%3E)
 
   `(A0, B0, C0)` -> `T3(A1, B1, C1)` where A1, B1, C1 have [From] A0, B0, C0 traits respectively
@@ -22,6 +30,10 @@ Implementations:
 
   `T3(A0, B0, C0)` -> `(A1, B1, C1)` where A1, B1, C1 have [From] A0, B0, C0 traits respectively
 
+- [tries to transform into native tuple](struct.T3.html#impl-TryFromElements%3CT3%3CA0%2C%20B0%2C%20C0%3E%3E-for-(A1%2C%20B1%2C%20C1))
+
+  `T3(A0, B0, C0)` -> `Result<(A1, B1, C1), T3Error<..>>` where A1, B1, C1 have [TryFrom] A0, B0, C0 traits respectively
+
 ## Ready to partition value wrappers
 
 Example: [P3]
@@ -37,6 +49,10 @@ Implementations:
 
   `&[N]` -> `(A, B, C)` where A, B, C have [From] `[T; AN]`, `[T; BN]`, `[T; CN]` traits respectively
 
+- [tracks field ranges](Spanned)
+
+  `P3(&[T])` -> `(Spanned<A>, Spanned<B>, Spanned<C>)` with `try_into_spanned()` method
+
 It also used to reduce records length of same type tuple wrappers:
 `T3<[u16; 1], [u16; 2], [u16; 3]>` equals to `P3<u16, 1, 2, 3>`
 
@@ -44,14 +60,20 @@ It also used to reduce records length of same type tuple wrappers:
 - [split sequnce into a head and a tail](Seq)
   `[1, 2, 3, 4, 5]` -> `Seq { head: [1, 2], tail: [3, 4, 5] }`
 
+## Heterogeneous lists
+[HCons] and [HNil] build lists of any length:
+`HCons<u8, 1, HCons<u16, 2, HNil>>` describes the same layout as `P2<(u8, u16), 1, 2>`.
+Lists up to 64 fields are converted to flat tuples with [IntoTuple].
+
 ## Type coercion wrappers
 `U16<ArbitraryType>` -> `u16` where `ArbitraryType` has `From<u16>` implementation
 
 */
 
 use core::array::TryFromSliceError;
+use core::ops::Range;
 
-use funty::Fundamental;
+use funty::{Fundamental, Integral};
 use paste::paste;
 
 /// Trait derives primitive types.
@@ -73,11 +95,45 @@ impl<T> AsPrimitive<()> for T {
     fn as_primitive(self) {}
 }
 
+/// Trait derives primitive types, rejecting values out of target type range
+///
+/// Checked counterpart of [AsPrimitive]. Implemented for integer sources only, float to integer
+/// casts lose fractional part that can't be detected by value comparison.
+/// ```compile_fail
+/// # use heterob::TryAsPrimitive;
+/// let _: Result<u8, _> = 1.5f32.try_as_primitive();
+/// ```
+pub trait TryAsPrimitive<T> {
+    type Error;
+    fn try_as_primitive(self) -> Result<T, Self::Error>;
+}
+
+/// Reserved space unit type implementation
+impl<T> TryAsPrimitive<()> for T {
+    type Error = T;
+    fn try_as_primitive(self) -> Result<(), T> {
+        Ok(())
+    }
+}
+
 macro_rules! main_impl_for {
     ( AsPrimitive => $($cl:ty),+ $(,)?) => {paste!{ $(
         impl<T: Fundamental> AsPrimitive<$cl> for T {
             fn as_primitive(self) -> $cl { self.[<as_ $cl>]() }
         }
+
+        /// Returns source value if it doesn't fit
+        impl<T: Integral> TryAsPrimitive<$cl> for T {
+            type Error = T;
+            fn try_as_primitive(self) -> Result<$cl, T> {
+                let value = self.[<as_ $cl>]();
+                if value.as_u128() == self.as_u128() {
+                    Ok(value)
+                } else {
+                    Err(self)
+                }
+            }
+        }
     )+ }};
 }
 
@@ -91,6 +147,45 @@ pub trait ParamAndAssociatedConst<const N: usize> {
     const ASSERT_EQ: (usize, usize) = (Self::LESS, Self::GREATER);
 }
 
+/// Fallible element-wise conversion
+///
+/// Counterpart of element-wise [From] implementations for tuple wrappers and partitions.
+/// It can't be expressed with std [TryFrom] because of conflict with its blanket
+/// implementation for types that implement [Into].
+///
+/// It is the reciprocal of [ElementsTryInto].
+pub trait TryFromElements<T>: Sized {
+    type Error;
+    fn try_from_elements(value: T) -> Result<Self, Self::Error>;
+}
+
+/// Fallible element-wise conversion that consumes the input value
+///
+/// The opposite of [TryFromElements].
+/// One should avoid implementing [ElementsTryInto] and implement [TryFromElements] instead.
+pub trait ElementsTryInto<T> {
+    type Error;
+    fn elements_try_into(self) -> Result<T, Self::Error>;
+}
+
+/// Implementing [TryFromElements] automatically provides one with an implementation of
+/// [ElementsTryInto] thanks to this blanket implementation.
+impl<T, U: TryFromElements<T>> ElementsTryInto<U> for T {
+    type Error = U::Error;
+    fn elements_try_into(self) -> Result<U, Self::Error> {
+        U::try_from_elements(self)
+    }
+}
+
+/// Error of fallible element-wise conversion from slice backed partition
+#[derive(Debug, Clone)]
+pub enum SliceElementsError<E> {
+    /// Slice is shorter than partition
+    Slice(TryFromSliceError),
+    /// Element conversion failed
+    Element(E),
+}
+
 /// Sequence of elements with head and tail
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Seq<H, T> {
@@ -98,6 +193,72 @@ pub struct Seq<H, T> {
     pub tail: T,
 }
 
+/**
+Value with range of bytes or bits it was decoded from
+
+Spans of nested partitions are relative to nested partition. They are composed with
+[Spanned::offset] or `fields_into()` method of spanned nested partitions.
+```rust
+# use heterob::{P2, P3, Seq, Spanned, endianness::Le};
+let data = [0x00, 0x11,0x22,0x33,0x44, 0x55, 0x66];
+
+let Seq { head: (header, payload, crc), tail } = P3::<_, 1, 4, 1>(&data[..])
+    .try_into_spanned::<([u8; 1], [u8; 4], [u8; 1])>()
+    .unwrap();
+assert_eq!((0..1, 5..6), (header.span, crc.span));
+assert_eq!(&[0x66], tail);
+
+// Fields of nested partition are shifted by partition span
+let Le((a, b)) = payload.map(P2::<_, 2, 2>).fields_into::<Le<(u16, u16)>>();
+assert_eq!(Spanned { value: 0x2211, span: 1..3 }, a);
+assert_eq!(Spanned { value: 0x4433, span: 3..5 }, b);
+```
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Spanned<T> {
+    pub value: T,
+    pub span: Range<usize>,
+}
+
+impl<T> Spanned<T> {
+    /// Same value with span moved by `offset`
+    pub fn offset(self, offset: usize) -> Self {
+        let Range { start, end } = self.span;
+        Self {
+            value: self.value,
+            span: start + offset..end + offset,
+        }
+    }
+
+    /// Same value with bytes span converted to bits span
+    ///
+    /// Used to compose bit partitions nested in bytes fields.
+    pub fn in_bits(self) -> Self {
+        let Range { start, end } = self.span;
+        Self {
+            value: self.value,
+            span: start * 8..end * 8,
+        }
+    }
+
+    /// Same span with converted value
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Spanned<U> {
+        Spanned {
+            value: f(self.value),
+            span: self.span,
+        }
+    }
+}
+
+/// Pairs every element of decoded tuple with its span
+///
+/// Implemented for tuples and for wrappers of tuples, e.g. `Le<(A, B)>` becomes
+/// `Le<(Spanned<A>, Spanned<B>)>`.
+pub trait WithSpans<const N: usize> {
+    type Output;
+    fn with_spans(self, spans: [Range<usize>; N]) -> Self::Output;
+}
+
 /**
 Fallible conversion from slice to array
 
@@ -111,7 +272,6 @@ let seq: Seq<[_; 3], _> = bytes[..].try_into().unwrap();
 assert_eq!(Seq { head: [1, 2, 2], tail: [3, 3, 3, 3].as_slice() }, seq);
 ```
 */
-
 impl<'a, T, const N: usize> TryFrom<&'a [T]> for Seq<[T; N], &'a [T]>
 where
     T: Copy,
@@ -127,6 +287,85 @@ where
     }
 }
 
+/**
+Fallible conversion from slice to array reference
+
+Borrowed counterpart of [`TryFrom<&[T]> for Seq<[T; N], &[T]>`](Seq) that does not copy
+elements and does not require `T: Copy`
+```rust
+# use heterob::Seq;
+let bytes = [1u8, 2, 2, 3, 3, 3, 3];
+let seq: Seq<&[_; 3], _> = bytes[..].try_into().unwrap();
+assert_eq!(Seq { head: &[1, 2, 2], tail: [3, 3, 3, 3].as_slice() }, seq);
+```
+*/
+impl<'a, T, const N: usize> TryFrom<&'a [T]> for Seq<&'a [T; N], &'a [T]> {
+    type Error = TryFromSliceError;
+
+    fn try_from(slice: &'a [T]) -> Result<Self, Self::Error> {
+        let (head, tail) = slice.split_at(slice.len().min(N));
+        Ok(Self {
+            head: head.try_into()?,
+            tail,
+        })
+    }
+}
+
+/**
+Fallible conversion from mutable slice to mutable array reference
+
+Allows to split buffer into disjoint mutable parts
+```rust
+# use heterob::Seq;
+let mut bytes = [0u8; 5];
+let Seq { head, tail }: Seq<&mut [_; 2], _> = bytes.as_mut_slice().try_into().unwrap();
+head.copy_from_slice(&[1, 1]);
+tail.fill(2);
+assert_eq!([1, 1, 2, 2, 2], bytes);
+```
+*/
+impl<'a, T, const N: usize> TryFrom<&'a mut [T]> for Seq<&'a mut [T; N], &'a mut [T]> {
+    type Error = TryFromSliceError;
+
+    fn try_from(slice: &'a mut [T]) -> Result<Self, Self::Error> {
+        let (head, tail) = slice.split_at_mut(slice.len().min(N));
+        Ok(Self {
+            head: head.try_into()?,
+            tail,
+        })
+    }
+}
+
+/// Moves next `N` elements out of iterator into array
+///
+/// Iterator must have at least `N` elements left. Callers guarantee this with
+/// [ParamAndAssociatedConst] compile time check.
+fn array_from_iter<T, I: Iterator<Item = T>, const N: usize>(iter: &mut I) -> [T; N] {
+    core::array::from_fn(|_| match iter.next() {
+        Some(value) => value,
+        None => unreachable!("array length is checked at compile time"),
+    })
+}
+
+/// Fallible counterpart of [core::array::from_fn] that stops on the first error
+pub(crate) fn try_array_from_fn<T, E, F, const N: usize>(mut f: F) -> Result<[T; N], E>
+where
+    F: FnMut(usize) -> Result<T, E>,
+{
+    let mut error = None;
+    let array: [Option<T>; N] = core::array::from_fn(|n| match error {
+        Some(_) => None,
+        None => f(n).map_err(|err| error = Some(err)).ok(),
+    });
+    match error {
+        Some(err) => Err(err),
+        None => Ok(array.map(|value| match value {
+            Some(value) => value,
+            None => unreachable!("all elements are set if there is no error"),
+        })),
+    }
+}
+
 macro_rules! main_alphabet {
     ($len:expr; $($cl:ident),+ $(,)?) => { paste!{
         // #[derive(Debug, Clone, PartialEq, Eq)]
@@ -148,6 +387,32 @@ macro_rules! main_alphabet {
             const VALUE: usize = 0 $(+ [<$cl N>])+;
         }
 
+        /*
+        impl<'a, T, const N: usize, const AN: usize, const BN: usize, const CN: usize>
+            ParamAndAssociatedConst<N> for T3<&'a [T; AN], &'a [T; BN], &'a [T; CN]>
+        {
+            const VALUE: usize = AN + BN + CN;
+        }
+        */
+        impl<'a, T, const N: usize, $(const [<$cl N>]:usize,)+> ParamAndAssociatedConst<N>
+            for [<T $len>]<$(&'a [T;[<$cl N>]],)+>
+        {
+            const VALUE: usize = 0 $(+ [<$cl N>])+;
+        }
+
+        /*
+        impl<'a, T, const N: usize, const AN: usize, const BN: usize, const CN: usize>
+            ParamAndAssociatedConst<N> for T3<&'a mut [T; AN], &'a mut [T; BN], &'a mut [T; CN]>
+        {
+            const VALUE: usize = AN + BN + CN;
+        }
+        */
+        impl<'a, T, const N: usize, $(const [<$cl N>]:usize,)+> ParamAndAssociatedConst<N>
+            for [<T $len>]<$(&'a mut [T;[<$cl N>]],)+>
+        {
+            const VALUE: usize = 0 $(+ [<$cl N>])+;
+        }
+
         /*
         impl<A0, A1, B0, B1, C0, C1> From<T3<A0, B0, C0>> for (A1, B1, C1)
         where
@@ -169,6 +434,91 @@ macro_rules! main_alphabet {
             }
         }
 
+        /*
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub enum T3Error<A, B, C> {
+            A(A),
+            B(B),
+            C(C),
+        }
+        */
+        #[doc=concat!("Error of fallible element-wise conversion of [T", $len, "], holds error of the first failed element")]
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub enum [<T $len Error>]<$($cl,)+> {
+            $($cl($cl),)+
+        }
+
+        impl<$($cl,)+> [<T $len Error>]<$($cl,)+> {
+            /// Index of failed element
+            pub fn index(&self) -> usize {
+                let mut _index = 0;
+                $(
+                    if matches!(self, Self::$cl(_)) {
+                        return _index;
+                    }
+                    _index += 1;
+                )+
+                unreachable!()
+            }
+        }
+
+        /*
+        impl<A0, A1, B0, B1, C0, C1> TryFromElements<T3<A0, B0, C0>> for (A1, B1, C1)
+        where
+            A1: TryFrom<A0>,
+            B1: TryFrom<B0>,
+            C1: TryFrom<C0>,
+        {
+            type Error = T3Error<A1::Error, B1::Error, C1::Error>;
+            fn try_from_elements(T3(a, b, c): T3<A0, B0, C0>) -> Result<Self, Self::Error> {
+                let a = A1::try_from(a).map_err(T3Error::A)?;
+                let b = B1::try_from(b).map_err(T3Error::B)?;
+                let c = C1::try_from(c).map_err(T3Error::C)?;
+                Ok((a, b, c))
+            }
+        }
+        */
+        impl<$([<$cl 0>],[<$cl 1>],)+> TryFromElements<[<T $len>]<$([<$cl 0>],)+>> for ($([<$cl 1>],)+)
+        where
+            $([<$cl 1>]: TryFrom<[<$cl 0>]>,)+
+        {
+            type Error = [<T $len Error>]<$(<[<$cl 1>] as TryFrom<[<$cl 0>]>>::Error,)+>;
+            fn try_from_elements([<T $len>]($([<$cl:lower>],)+): [<T $len>]<$([<$cl 0>],)+>) ->
+                Result<Self, Self::Error>
+            {
+                $(
+                    let [<$cl:lower>] =
+                        [<$cl 1>]::try_from([<$cl:lower>]).map_err([<T $len Error>]::$cl)?;
+                )+
+                Ok(($([<$cl:lower>],)+))
+            }
+        }
+
+        /*
+        impl<A0, A1, B0, B1, C0, C1> TryFromElements<(A0, B0, C0)> for T3<A1, B1, C1>
+        where
+            A1: TryFrom<A0>,
+            B1: TryFrom<B0>,
+            C1: TryFrom<C0>,
+        {
+            type Error = T3Error<A1::Error, B1::Error, C1::Error>;
+            fn try_from_elements(data: (A0, B0, C0)) -> Result<Self, Self::Error> {
+                let (a, b, c) = T3::from(data).elements_try_into()?;
+                Ok(T3(a, b, c))
+            }
+        }
+        */
+        impl<$([<$cl 0>],[<$cl 1>],)+> TryFromElements<($([<$cl 0>],)+)> for [<T $len>]<$([<$cl 1>],)+>
+        where
+            $([<$cl 1>]: TryFrom<[<$cl 0>]>,)+
+        {
+            type Error = [<T $len Error>]<$(<[<$cl 1>] as TryFrom<[<$cl 0>]>>::Error,)+>;
+            fn try_from_elements(data: ($([<$cl 0>],)+)) -> Result<Self, Self::Error> {
+                let ($([<$cl:lower>],)+) = [<T $len>]::from(data).elements_try_into()?;
+                Ok(Self($([<$cl:lower>],)+))
+            }
+        }
+
         /*
         impl<A0, A1, B0, B1, C0, C1> From<(A0, B0, C0)> for T3<A1, B1, C1>
         where
@@ -193,50 +543,94 @@ macro_rules! main_alphabet {
         /*
         impl<T, const N: usize, const AN: usize, const BN: usize, const CN: usize>
             From<[T; N]> for T3<[T; AN], [T; BN], [T; CN]>
-        where
-            T: Default + Copy,
         {
             fn from(data: [T; N]) -> Self {
                 #![allow(path_statements)]
                 <Self as ParamAndAssociatedConst<N>>::ASSERT_EQ;
 
-                let end = 0;
-
-                let mut a = [Default::default(); AN];
-                let (start, end) = (end, end + AN);
-                a.copy_from_slice(&data[start..end]);
-
-                let mut b = [Default::default(); BN];
-                let (start, end) = (end, end + BN);
-                b.copy_from_slice(&data[start..end]);
-
-                let mut c = [Default::default(); CN];
-                let (start, end) = (end, end + CN);
-                c.copy_from_slice(&data[start..end]);
-
+                let mut data = data.into_iter();
+                let a = array_from_iter(&mut data);
+                let b = array_from_iter(&mut data);
+                let c = array_from_iter(&mut data);
                 T3(a, b, c)
             }
         }
         */
         impl<T, const N: usize, $(const [<$cl N>]:usize,)+>
             From<[T; N]> for [<T $len>]<$([T;[<$cl N>]],)+>
-        where
-            T: Default + Copy,
         {
             fn from(data: [T; N]) -> Self {
                 #![allow(path_statements)]
                 <Self as ParamAndAssociatedConst::<N>>::ASSERT_EQ;
 
-                let end = 0;
-                $(
-                    let mut [<$cl:lower>] = [Default::default();[<$cl N>]];
-                    let (start, end) = (end, end + [<$cl N>]);
-                    [<$cl:lower>].copy_from_slice(&data[start..end]);
-                )+
+                let mut data = data.into_iter();
+                $(let [<$cl:lower>] = array_from_iter(&mut data);)+
                 Self($([<$cl:lower>],)+)
             }
         }
 
+        /*
+        impl<'a, T, const N: usize, const AN: usize, const BN: usize, const CN: usize>
+            From<&'a [T; N]> for T3<&'a [T; AN], &'a [T; BN], &'a [T; CN]>
+        {
+            fn from(data: &'a [T; N]) -> Self {
+                #![allow(path_statements)]
+                <Self as ParamAndAssociatedConst<N>>::ASSERT_EQ;
+
+                let _data = data.as_slice();
+                let (a, _data) = _data.split_at(AN);
+                let (b, _data) = _data.split_at(BN);
+                let (c, _data) = _data.split_at(CN);
+                // Lengths are checked at compile time, so conversions never fail
+                T3(a.try_into().unwrap(), b.try_into().unwrap(), c.try_into().unwrap())
+            }
+        }
+        */
+        impl<'a, T, const N: usize, $(const [<$cl N>]:usize,)+>
+            From<&'a [T; N]> for [<T $len>]<$(&'a [T;[<$cl N>]],)+>
+        {
+            fn from(data: &'a [T; N]) -> Self {
+                #![allow(path_statements)]
+                <Self as ParamAndAssociatedConst::<N>>::ASSERT_EQ;
+
+                let _data = data.as_slice();
+                $(let ([<$cl:lower>], _data) = _data.split_at([<$cl N>]);)+
+                // Lengths are checked at compile time, so conversions never fail
+                Self($([<$cl:lower>].try_into().unwrap(),)+)
+            }
+        }
+
+        /*
+        impl<'a, T, const N: usize, const AN: usize, const BN: usize, const CN: usize>
+            From<&'a mut [T; N]> for T3<&'a mut [T; AN], &'a mut [T; BN], &'a mut [T; CN]>
+        {
+            fn from(data: &'a mut [T; N]) -> Self {
+                #![allow(path_statements)]
+                <Self as ParamAndAssociatedConst<N>>::ASSERT_EQ;
+
+                let _data = data.as_mut_slice();
+                let (a, _data) = _data.split_at_mut(AN);
+                let (b, _data) = _data.split_at_mut(BN);
+                let (c, _data) = _data.split_at_mut(CN);
+                // Lengths are checked at compile time, so conversions never fail
+                T3(a.try_into().unwrap(), b.try_into().unwrap(), c.try_into().unwrap())
+            }
+        }
+        */
+        impl<'a, T, const N: usize, $(const [<$cl N>]:usize,)+>
+            From<&'a mut [T; N]> for [<T $len>]<$(&'a mut [T;[<$cl N>]],)+>
+        {
+            fn from(data: &'a mut [T; N]) -> Self {
+                #![allow(path_statements)]
+                <Self as ParamAndAssociatedConst::<N>>::ASSERT_EQ;
+
+                let _data = data.as_mut_slice();
+                $(let ([<$cl:lower>], _data) = _data.split_at_mut([<$cl N>]);)+
+                // Lengths are checked at compile time, so conversions never fail
+                Self($([<$cl:lower>].try_into().unwrap(),)+)
+            }
+        }
+
         // #[derive(Debug, Clone, PartialEq, Eq)]
         // pub struct P3<TY, const A: usize, const B: usize, const C: usize>(pub TY);
         #[doc=concat!("Type wrapper with ", $len, " const generic parameters")]
@@ -244,16 +638,146 @@ macro_rules! main_alphabet {
         pub struct [<P $len>]<TY, $(const $cl: usize,)+>(pub TY);
         // impl<TY, const AN: usize, const BN: usize, const CN: usize> P3<TY, AN, BN, CN> {
         //     pub const SUM: usize = AN + BN + CN;
+        //     pub const WIDTHS: [usize; 3] = [AN, BN, CN];
+        //     pub const OFFSETS: [usize; 3] = [0, AN, AN + BN];
         // }
         impl<TY, $(const [<$cl N>]: usize,)+> [<P $len>]<TY,$([<$cl N>],)+> {
             pub const SUM: usize = 0 $(+ [<$cl N>])+;
+            /// Field widths
+            pub const WIDTHS: [usize; $len] = [$([<$cl N>]),+];
+            /// Field offsets from the beginning of partition
+            pub const OFFSETS: [usize; $len] = {
+                let mut offsets = [0; $len];
+                let mut i = 1;
+                while i < $len {
+                    offsets[i] = offsets[i - 1] + Self::WIDTHS[i - 1];
+                    i += 1;
+                }
+                offsets
+            };
+
+            /// Field ranges from the beginning of partition
+            pub fn spans() -> [Range<usize>; $len] {
+                core::array::from_fn(|n| Self::OFFSETS[n]..Self::OFFSETS[n] + Self::WIDTHS[n])
+            }
+        }
+
+        /*
+        impl<A, B, C> WithSpans<3> for (A, B, C) {
+            type Output = (Spanned<A>, Spanned<B>, Spanned<C>);
+            fn with_spans(self, spans: [Range<usize>; 3]) -> Self::Output {
+                let (a, b, c) = self;
+                let [a_span, b_span, c_span] = spans;
+                (
+                    Spanned { value: a, span: a_span },
+                    Spanned { value: b, span: b_span },
+                    Spanned { value: c, span: c_span },
+                )
+            }
+        }
+        */
+        impl<$($cl,)+> WithSpans<$len> for ($($cl,)+) {
+            type Output = ($(Spanned<$cl>,)+);
+            fn with_spans(self, spans: [Range<usize>; $len]) -> Self::Output {
+                let ($([<$cl:lower>],)+) = self;
+                let [$([<$cl:lower _span>],)+] = spans;
+                ($(Spanned { value: [<$cl:lower>], span: [<$cl:lower _span>] },)+)
+            }
+        }
+
+        /*
+        impl<'a, TY: Copy, const AN: usize, const BN: usize, const CN: usize> P3<&'a [TY], AN, BN, CN> {
+            pub fn try_into_spanned<U>(self) -> Result<Seq<U::Output, &'a [TY]>, TryFromSliceError>
+            where
+                U: From<T3<[TY; AN], [TY; BN], [TY; CN]>> + WithSpans<3>,
+            {
+                let Seq { head, tail }: Seq<U, _> = self.try_into()?;
+                Ok(Seq { head: head.with_spans(Self::spans()), tail })
+            }
+        }
+        */
+        impl<'a, TY: Copy, $(const [<$cl N>]: usize,)+> [<P $len>]<&'a [TY], $([<$cl N>],)+> {
+            /// Slice conversion that pairs every field with its range within slice
+            pub fn try_into_spanned<U>(self) -> Result<Seq<U::Output, &'a [TY]>, TryFromSliceError>
+            where
+                U: From<[<T $len>]<$([TY; [<$cl N>]],)+>> + WithSpans<$len>,
+            {
+                let Seq { head, tail }: Seq<U, _> = self.try_into()?;
+                Ok(Seq { head: head.with_spans(Self::spans()), tail })
+            }
+        }
+
+        /*
+        impl<TY, const NU: usize, const AN: usize, const BN: usize, const CN: usize>
+            Spanned<P3<[TY; NU], AN, BN, CN>>
+        {
+            pub fn fields_into<U>(self) -> U::Output
+            where
+                U: From<P3<[TY; NU], AN, BN, CN>> + WithSpans<3>,
+            {
+                let Spanned { value, span } = self;
+                let spans = P3::<[TY; NU], AN, BN, CN>::spans()
+                    .map(|Range { start, end }| start + span.start..end + span.start);
+                U::from(value).with_spans(spans)
+            }
+        }
+        */
+        impl<TY, const NU: usize, $(const [<$cl N>]: usize,)+>
+            Spanned<[<P $len>]<[TY; NU], $([<$cl N>],)+>>
+        {
+            /// Converts nested partition into fields with spans shifted by partition span
+            pub fn fields_into<U>(self) -> U::Output
+            where
+                U: From<[<P $len>]<[TY; NU], $([<$cl N>],)+>> + WithSpans<$len>,
+            {
+                let Spanned { value, span } = self;
+                let spans = [<P $len>]::<[TY; NU], $([<$cl N>],)+>::spans()
+                    .map(|Range { start, end }| start + span.start..end + span.start);
+                U::from(value).with_spans(spans)
+            }
+        }
+
+        /*
+        impl<const N: usize, const AN: usize, const BN: usize, const CN: usize> P3<[u8; N], AN, BN, CN> {
+            pub const fn split(self) -> ([u8; AN], [u8; BN], [u8; CN]) {
+                <T3<[u8; AN], [u8; BN], [u8; CN]> as ParamAndAssociatedConst<N>>::ASSERT_EQ;
+                let mut _offset = 0;
+                let mut a = [0; AN];
+                let mut pos = 0;
+                while pos < AN {
+                    a[pos] = self.0[_offset + pos];
+                    pos += 1;
+                }
+                _offset += AN;
+                ..
+                (a, b, c)
+            }
+        }
+        */
+        impl<const NU: usize, $(const [<$cl N>]: usize,)+> [<P $len>]<[u8; NU], $([<$cl N>],)+> {
+            /// Const fn split of bytes into arrays
+            #[allow(path_statements)]
+            pub const fn split(self) -> ($([u8; [<$cl N>]],)+) {
+                <[<T $len>]<$([u8; [<$cl N>]],)+> as ParamAndAssociatedConst<NU>>::ASSERT_EQ;
+
+                let mut _offset = 0;
+                $(
+                    let mut [<$cl:lower>] = [0; [<$cl N>]];
+                    let mut pos = 0;
+                    while pos < [<$cl N>] {
+                        [<$cl:lower>][pos] = self.0[_offset + pos];
+                        pos += 1;
+                    }
+                    _offset += [<$cl N>];
+                )+
+                ($([<$cl:lower>],)+)
+            }
         }
 
         /*
         impl<TY,A,B,C, const N: usize, const AN: usize, const BN: usize, const CN: usize>
             From<P3<[TY;N],AN,BN,CN>> for (A,B,C,)
         where
-            TY: Copy + Default,
             A: From<[TY;AN]>,
             B: From<[TY;BN]>,
             C: From<[TY;CN]>,
@@ -266,7 +790,6 @@ macro_rules! main_alphabet {
         impl<TY, $($cl,)+ const NU: usize, $(const [<$cl N>]: usize,)+>
             From<[<P $len>]<[TY;NU],$([<$cl N>],)+>> for ($($cl,)+)
         where
-            TY: Copy + Default,
             $($cl: From<[TY;[<$cl N>]]>,)+
         {
             fn from([<P $len>](data): [<P $len>]<[TY;NU],$([<$cl N>],)+>) -> Self {
@@ -274,6 +797,33 @@ macro_rules! main_alphabet {
             }
         }
 
+        /*
+        impl<TY,A,B,C, const N: usize, const AN: usize, const BN: usize, const CN: usize>
+            TryFromElements<P3<[TY;N],AN,BN,CN>> for (A,B,C,)
+        where
+            A: TryFrom<[TY;AN]>,
+            B: TryFrom<[TY;BN]>,
+            C: TryFrom<[TY;CN]>,
+        {
+            type Error = T3Error<A::Error, B::Error, C::Error>;
+            fn try_from_elements(P3(data): P3<[TY;N],AN,BN,CN>) -> Result<Self, Self::Error> {
+                T3::<[TY;AN],[TY;BN],[TY;CN]>::from(data).elements_try_into()
+            }
+        }
+        */
+        impl<TY, $($cl,)+ const NU: usize, $(const [<$cl N>]: usize,)+>
+            TryFromElements<[<P $len>]<[TY;NU],$([<$cl N>],)+>> for ($($cl,)+)
+        where
+            $($cl: TryFrom<[TY;[<$cl N>]]>,)+
+        {
+            type Error = [<T $len Error>]<$(<$cl as TryFrom<[TY;[<$cl N>]]>>::Error,)+>;
+            fn try_from_elements([<P $len>](data): [<P $len>]<[TY;NU],$([<$cl N>],)+>) ->
+                Result<Self, Self::Error>
+            {
+                [<T $len>]::<$([TY;[<$cl N>]],)+>::from(data).elements_try_into()
+            }
+        }
+
         /*
         impl<'a, T, const AN: usize, const BN: usize, const CN: usize> TryFrom<&'a [T]>
             for Seq<T3<[T; AN], [T; BN], [T; CN]>, &'a [T]>
@@ -312,6 +862,74 @@ macro_rules! main_alphabet {
             }
         }
 
+        /*
+        impl<'a, T, const AN: usize, const BN: usize, const CN: usize> TryFrom<&'a [T]>
+            for Seq<T3<&'a [T; AN], &'a [T; BN], &'a [T; CN]>, &'a [T]>
+        {
+            type Error = TryFromSliceError;
+
+            fn try_from(slice: &'a [T]) -> Result<Self, Self::Error> {
+                let Seq { head: a, tail: slice }: Seq<&[T; AN], &[T]> = slice.try_into()?;
+                let Seq { head: b, tail: slice }: Seq<&[T; BN], &[T]> = slice.try_into()?;
+                let Seq { head: c, tail: slice }: Seq<&[T; CN], &[T]> = slice.try_into()?;
+                Ok(Self {
+                    head: T3(a, b, c),
+                    tail: slice,
+                })
+            }
+        }
+        */
+        impl<'a, T, $(const [<$cl N>]:usize,)+> TryFrom<&'a [T]>
+            for Seq<[<T $len>]<$(&'a [T;[<$cl N>]],)+>, &'a [T]>
+        {
+            type Error = TryFromSliceError;
+
+            fn try_from(slice: &'a [T]) -> Result<Self, Self::Error> {
+                $(
+                    let Seq { head: [<$cl:lower>], tail: slice }: Seq<&[T;[<$cl N>]], &[T]> =
+                        slice.try_into()?;
+                )+
+                Ok(Self {
+                    head: [<T $len>]($([<$cl:lower>],)+),
+                    tail: slice,
+                })
+            }
+        }
+
+        /*
+        impl<'a, T, const AN: usize, const BN: usize, const CN: usize> TryFrom<&'a mut [T]>
+            for Seq<T3<&'a mut [T; AN], &'a mut [T; BN], &'a mut [T; CN]>, &'a mut [T]>
+        {
+            type Error = TryFromSliceError;
+
+            fn try_from(slice: &'a mut [T]) -> Result<Self, Self::Error> {
+                let Seq { head: a, tail: slice }: Seq<&mut [T; AN], &mut [T]> = slice.try_into()?;
+                let Seq { head: b, tail: slice }: Seq<&mut [T; BN], &mut [T]> = slice.try_into()?;
+                let Seq { head: c, tail: slice }: Seq<&mut [T; CN], &mut [T]> = slice.try_into()?;
+                Ok(Self {
+                    head: T3(a, b, c),
+                    tail: slice,
+                })
+            }
+        }
+        */
+        impl<'a, T, $(const [<$cl N>]:usize,)+> TryFrom<&'a mut [T]>
+            for Seq<[<T $len>]<$(&'a mut [T;[<$cl N>]],)+>, &'a mut [T]>
+        {
+            type Error = TryFromSliceError;
+
+            fn try_from(slice: &'a mut [T]) -> Result<Self, Self::Error> {
+                $(
+                    let Seq { head: [<$cl:lower>], tail: slice }: Seq<&mut [T;[<$cl N>]], &mut [T]> =
+                        slice.try_into()?;
+                )+
+                Ok(Self {
+                    head: [<T $len>]($([<$cl:lower>],)+),
+                    tail: slice,
+                })
+            }
+        }
+
         /*
         impl<'a, T, U, const AN: usize, const BN: usize, const CN: usize>
             TryFrom<P3<&'a [T], AN, BN, CN>> for Seq<U, &'a [T]>
@@ -346,6 +964,43 @@ macro_rules! main_alphabet {
                 })
             }
         }
+
+        /*
+        impl<'a, T, A, B, C, const AN: usize, const BN: usize, const CN: usize>
+            TryFromElements<P3<&'a [T], AN, BN, CN>> for Seq<(A, B, C), &'a [T]>
+        where
+            T: Copy,
+            A: TryFrom<[T; AN]>,
+            B: TryFrom<[T; BN]>,
+            C: TryFrom<[T; CN]>,
+        {
+            type Error = SliceElementsError<T3Error<A::Error, B::Error, C::Error>>;
+            fn try_from_elements(P3(data): P3<&'a [T], AN, BN, CN>) -> Result<Self, Self::Error> {
+                let Seq { head, tail }: Seq<T3<[T; AN], [T; BN], [T; CN]>, _> =
+                    data.try_into().map_err(SliceElementsError::Slice)?;
+                let head = head.elements_try_into().map_err(SliceElementsError::Element)?;
+                Ok(Seq { head, tail })
+            }
+        }
+        */
+        impl<'a, TY, $($cl,)+ $(const [<$cl N>]: usize,)+>
+            TryFromElements<[<P $len>]<&'a [TY], $([<$cl N>],)+>> for Seq<($($cl,)+), &'a [TY]>
+        where
+            TY: Copy,
+            $($cl: TryFrom<[TY;[<$cl N>]]>,)+
+        {
+            type Error = SliceElementsError<
+                [<T $len Error>]<$(<$cl as TryFrom<[TY;[<$cl N>]]>>::Error,)+>
+            >;
+            fn try_from_elements([<P $len>](data): [<P $len>]<&'a [TY], $([<$cl N>],)+>) ->
+                Result<Self, Self::Error>
+            {
+                let Seq { head, tail }: Seq<[<T $len>]<$([TY;[<$cl N>]],)+>, _> =
+                    data.try_into().map_err(SliceElementsError::Slice)?;
+                let head = head.elements_try_into().map_err(SliceElementsError::Element)?;
+                Ok(Seq { head, tail })
+            }
+        }
     }};
 }
 
@@ -402,6 +1057,92 @@ main_alphabet!(25; A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T,U,V,W,X,Y);
 // Sexvigintuple
 main_alphabet!(26; A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T,U,V,W,X,Y,Z);
 
+/// Heterogeneous list node
+///
+/// Recursive alternative to tuple wrappers without arity limit. `HN` is a head width
+/// (bytes or bits), tail is another [HCons] or [HNil].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HCons<H, const HN: usize, T>(pub H, pub T);
+
+/// Heterogeneous list end
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HNil;
+
+impl<const N: usize> ParamAndAssociatedConst<N> for HNil {
+    const VALUE: usize = 0;
+}
+
+impl<H, T, const N: usize, const HN: usize> ParamAndAssociatedConst<N> for HCons<H, HN, T>
+where
+    T: ParamAndAssociatedConst<N>,
+{
+    const VALUE: usize = HN + T::VALUE;
+}
+
+/**
+Heterogeneous list to flat tuple conversion
+
+Implemented for lists of 1 to 64 fields.
+```rust
+# use heterob::{HCons, HNil, IntoTuple, bit_numbering::LsbInto};
+type L = HCons<u8, 4, HCons<(), 10, HCons<u8, 2, HNil>>>;
+
+let list: L = 0b1011_0000_0000_1011u16.lsb_into();
+let (a, (), b) = list.into_tuple();
+assert_eq!((0b1011, 0b10), (a, b));
+```
+*/
+pub trait IntoTuple {
+    type Tuple;
+    fn into_tuple(self) -> Self::Tuple;
+}
+
+macro_rules! hlist_tuple {
+    (@type) => { HNil };
+    (@type $h:ident $hn:ident $($t:ident $tn:ident)*) => { HCons<$h, $hn, hlist_tuple!(@type $($t $tn)*)> };
+    (@pat) => { HNil };
+    (@pat $h:ident $($t:ident)*) => { HCons($h, hlist_tuple!(@pat $($t)*)) };
+    /*
+    impl<F0, F1, F2, const F0N: usize, const F1N: usize, const F2N: usize> IntoTuple
+        for HCons<F0, F0N, HCons<F1, F1N, HCons<F2, F2N, HNil>>>
+    {
+        type Tuple = (F0, F1, F2);
+        fn into_tuple(self) -> Self::Tuple {
+            let HCons(F0, HCons(F1, HCons(F2, HNil))) = self;
+            (F0, F1, F2)
+        }
+    }
+    */
+    (@impl $($cl:ident)+) => { paste!{
+        impl<$($cl,)+ $(const [<$cl N>]: usize,)+> IntoTuple for hlist_tuple!(@type $($cl [<$cl N>])+) {
+            type Tuple = ($($cl,)+);
+            #[allow(non_snake_case)]
+            fn into_tuple(self) -> Self::Tuple {
+                let hlist_tuple!(@pat $($cl)+) = self;
+                ($($cl,)+)
+            }
+        }
+    }};
+    ([$($done:ident)*]) => {};
+    ([$($done:ident)*] $next:ident $($rest:ident)*) => {
+        hlist_tuple!(@impl $($done)* $next);
+        hlist_tuple!([$($done)* $next] $($rest)*);
+    };
+}
+
+// Split in two invocations to stay within macro recursion limit
+hlist_tuple!([]
+    F0 F1 F2 F3 F4 F5 F6 F7 F8 F9 F10 F11 F12 F13 F14 F15
+    F16 F17 F18 F19 F20 F21 F22 F23 F24 F25 F26 F27 F28 F29 F30 F31
+);
+hlist_tuple!([
+    F0 F1 F2 F3 F4 F5 F6 F7 F8 F9 F10 F11 F12 F13 F14 F15
+    F16 F17 F18 F19 F20 F21 F22 F23 F24 F25 F26 F27 F28 F29 F30 F31
+]
+    F32 F33 F34 F35 F36 F37 F38 F39 F40 F41 F42 F43 F44 F45 F46 F47
+    F48 F49 F50 F51 F52 F53 F54 F55 F56 F57 F58 F59 F60 F61 F62 F63
+);
+
 // #[derive(Debug, Clone, PartialEq, Eq)]
 // pub struct U8<T>(T);
 
@@ -422,6 +1163,13 @@ macro_rules! impl_wrappers_as_primitive {
                 $ty(self.[<as_ $ty:lower>]().into())
             }
         }
+
+        impl<T: From<[<$ty:lower>]>, U: Integral> TryAsPrimitive<$ty<T>> for U {
+            type Error = U;
+            fn try_as_primitive(self) -> Result<$ty<T>, U> {
+                TryAsPrimitive::<[<$ty:lower>]>::try_as_primitive(self).map(|v| $ty(v.into()))
+            }
+        }
     )+ }};
 }
 
@@ -430,6 +1178,75 @@ impl_wrappers_as_primitive!(Bool, U8, U16, U32, U64, U128, Usize);
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::{string::String, vec::Vec};
+
+    #[test]
+    fn try_as_primitive() {
+        assert_eq!(Ok(255u8), 255u16.try_as_primitive());
+        assert_eq!(Err(256u16), TryAsPrimitive::<u8>::try_as_primitive(256u16));
+        assert_eq!(Ok(true), 1u8.try_as_primitive());
+        assert_eq!(Err(2u8), TryAsPrimitive::<bool>::try_as_primitive(2u8));
+        assert_eq!(Err(-1i8), TryAsPrimitive::<u16>::try_as_primitive(-1i8));
+        assert_eq!(Ok(U16(0xFFFFu32)), 0xFFFFu64.try_as_primitive());
+        assert_eq!(Ok(()), u128::MAX.try_as_primitive());
+    }
+
+    #[test]
+    fn spanned_slice_try_into() {
+        use crate::endianness::{Be, Le};
+        let data = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77];
+
+        assert_eq!([0..1, 1..3, 3..7], P3::<(), 1, 2, 4>::spans());
+
+        let Seq { head, tail } = P3::<_, 1, 2, 4>(&data[..])
+            .try_into_spanned::<Le<(u8, u16, u32)>>()
+            .unwrap();
+        let sample = Le((
+            Spanned { value: 0x00, span: 0..1 },
+            Spanned { value: 0x2211, span: 1..3 },
+            Spanned { value: 0x66554433, span: 3..7 },
+        ));
+        assert_eq!(sample, head);
+        assert_eq!(&[0x77], tail);
+
+        let Seq { head: Be((a, b)), .. } = P2::<_, 2, 6>(&data[..])
+            .try_into_spanned::<Be<(u16, [u8; 6])>>()
+            .unwrap();
+        assert_eq!(Spanned { value: 0x0011, span: 0..2 }, a);
+        let Be((c, d, e)) = b.map(P3::<_, 2, 2, 2>).fields_into::<Be<(u16, [u8; 2], u16)>>();
+        assert_eq!(Spanned { value: 0x2233, span: 2..4 }, c, "nested partition");
+        assert_eq!(Spanned { value: [0x44, 0x55], span: 4..6 }, d, "nested partition");
+        assert_eq!(Spanned { value: 0x6677, span: 6..8 }, e, "nested partition");
+
+        let result = P2::<_, 4, 5>(&data[..]).try_into_spanned::<([u8; 4], [u8; 5])>();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn spanned_offset() {
+        let value = Spanned { value: (), span: 1..3 };
+        assert_eq!(5..7, value.clone().offset(4).span);
+        assert_eq!(8..24, value.clone().in_bits().span);
+        assert_eq!(Spanned { value: 1, span: 1..3 }, value.map(|()| 1));
+    }
+
+    #[test]
+    fn partition_layout() {
+        type P = P4<u32, 3, 5, 8, 16>;
+        assert_eq!([3, 5, 8, 16], P::WIDTHS);
+        assert_eq!([0, 3, 8, 16], P::OFFSETS);
+        assert_eq!([0], P1::<u8, 8>::OFFSETS);
+
+        let widths = [3, 5, 8, 16];
+        assert!(matches!(widths, P::WIDTHS), "const pattern");
+    }
+
+    #[test]
+    fn const_fn_split_bytes() {
+        const PARTS: ([u8; 1], [u8; 2], [u8; 4]) = P3::<_, 1, 2, 4>([1, 2, 2, 3, 3, 3, 3]).split();
+        assert_eq!(([1], [2, 2], [3, 3, 3, 3]), PARTS);
+        assert_eq!(([], [1]), P2::<_, 0, 1>([1]).split(), "zero length");
+    }
 
     #[test]
     fn split_array() {
@@ -440,6 +1257,47 @@ mod tests {
         assert_eq!(T3([1], [2, 2], [3, 3, 3, 3]), T3::from(bytes));
     }
 
+    #[test]
+    fn split_array_of_non_copy() {
+        let strings = [String::from("a"), String::from("b"), String::from("b")];
+        let T2(a, b): T2<[String; 1], [String; 2]> = strings.into();
+        assert_eq!(["a"], a.each_ref().map(String::as_str));
+        assert_eq!(["b", "b"], b.each_ref().map(String::as_str));
+
+        #[derive(Debug, PartialEq, Eq)]
+        enum NoDefault {
+            A,
+            B,
+        }
+        let (a, b): ([_; 1], Vec<_>) = P2::<_, 1, 1>([NoDefault::A, NoDefault::B]).into();
+        assert_eq!(([NoDefault::A], vec![NoDefault::B]), (a, b));
+    }
+
+    #[test]
+    fn split_array_ref() {
+        let strings = [
+            String::from("a"),
+            String::from("b"),
+            String::from("b"),
+        ];
+        let T2(a, b): T2<&[_; 1], &[_; 2]> = (&strings).into();
+        assert_eq!((&strings[..1], &strings[1..]), (&a[..], &b[..]));
+        assert!(core::ptr::eq(&strings[1], &b[0]), "borrowed, not copied");
+
+        let bytes = [1u8, 2, 2, 3, 3, 3, 3];
+        assert_eq!(T3(&[1], &[2, 2], &[3, 3, 3, 3]), T3::from(&bytes));
+    }
+
+    #[test]
+    fn split_array_mut() {
+        let mut bytes = [0u8; 7];
+        let T3(a, b, c): T3<&mut [_; 1], &mut [_; 2], &mut [_; 4]> = (&mut bytes).into();
+        *a = [1];
+        b.fill(2);
+        c.copy_from_slice(&[3, 3, 3, 3]);
+        assert_eq!([1, 2, 2, 3, 3, 3, 3], bytes);
+    }
+
     #[test]
     fn tuple_of_elements_from() {
         assert_eq!((97u128, 98usize), T2(97u8, 98u16).into());
@@ -452,6 +1310,61 @@ mod tests {
         assert_eq!(T3('a', 'b', 'c'), (97, 98, 99).into());
     }
 
+    #[test]
+    fn tuple_of_elements_try_from() {
+        use core::num::NonZeroU8;
+
+        let result: Result<(NonZeroU8, u8, char), _> = T3(1u8, 2u32, 99u32).elements_try_into();
+        assert_eq!(Ok((NonZeroU8::new(1).unwrap(), 2, 'c')), result);
+
+        let result: Result<(NonZeroU8, u8, char), _> = T3(1u8, 256u32, 0xD800u32).elements_try_into();
+        assert_eq!(Some(1), result.map_err(|e| e.index()).err(), "first failed element");
+
+        let result: Result<T2<u8, NonZeroU8>, _> = (1u16, 0u8).elements_try_into();
+        assert!(matches!(result, Err(T2Error::B(_))));
+    }
+
+    #[test]
+    fn partition_elements_try_from() {
+        #[derive(Debug, PartialEq, Eq)]
+        struct Even(u8);
+        impl TryFrom<[u8; 1]> for Even {
+            type Error = u8;
+            fn try_from([byte]: [u8; 1]) -> Result<Self, Self::Error> {
+                if byte % 2 == 0 {
+                    Ok(Self(byte))
+                } else {
+                    Err(byte)
+                }
+            }
+        }
+
+        let result = P3::<_, 1, 2, 1>([2u8, 3, 3, 4]).elements_try_into();
+        assert_eq!(Ok((Even(2), [3, 3], Even(4))), result, "array");
+
+        let result: Result<(Even, [u8; 2], Even), _> = P3::<_, 1, 2, 1>([2u8, 3, 3, 5]).elements_try_into();
+        assert_eq!(Err(T3Error::C(5)), result, "array with failed element");
+
+        let bytes = [2u8, 3, 3, 4, 5];
+        let result = P3::<_, 1, 2, 1>(bytes.as_slice()).elements_try_into();
+        let sample = Seq {
+            head: (Even(2), [3, 3], Even(4)),
+            tail: &bytes[4..],
+        };
+        assert_eq!(Some(sample), result.ok(), "slice");
+
+        let result: Result<Seq<(Even, [u8; 2], Even), _>, _> =
+            P3::<_, 1, 2, 1>(&bytes[1..]).elements_try_into();
+        assert!(
+            matches!(result, Err(SliceElementsError::Element(T3Error::A(3)))),
+            "slice with failed element"
+        );
+
+        let result: Result<Seq<(Even, [u8; 2], Even), _>, _> =
+            P3::<_, 1, 2, 1>(&bytes[2..]).elements_try_into();
+        assert!(matches!(result, Err(SliceElementsError::Slice(_))), "slice is shorter");
+    }
+
     #[test]
     fn slice_try_into_tuple_of_arrays() {
         let bytes = [1u8, 2, 2, 3, 3, 3, 3];
@@ -461,6 +1374,48 @@ mod tests {
         assert_eq!(([1], [2, 2], [3, 3, 3]), (a, b, c));
     }
 
+    #[test]
+    fn slice_try_into_tuple_of_array_refs() {
+        let strings = [
+            String::from("a"),
+            String::from("b"),
+            String::from("b"),
+            String::from("c"),
+        ];
+        let Seq {
+            head: T2(a, b),
+            tail,
+        }: Seq<T2<&[_; 1], &[_; 2]>, _> = strings[..].try_into().unwrap();
+        assert_eq!(["a"], a.each_ref().map(String::as_str));
+        assert_eq!(["b", "b"], b.each_ref().map(String::as_str));
+        assert_eq!(&strings[3..], tail);
+
+        #[allow(clippy::type_complexity)]
+        let result: Option<Seq<T2<&[u8; 1], &[u8; 2]>, &[u8]>> = [1u8, 2][..].try_into().ok();
+        assert_eq!(None, result, "slice is shorter");
+    }
+
+    #[test]
+    fn mut_slice_try_into_tuple_of_array_muts() {
+        let mut bytes = [0u8; 8];
+        let Seq {
+            head: T3(a, b, c),
+            tail,
+        }: Seq<T3<&mut [_; 1], &mut [_; 2], &mut [_; 4]>, _> =
+            bytes.as_mut_slice().try_into().unwrap();
+        *a = [1];
+        b.fill(2);
+        c.fill(3);
+        tail[0] = 42;
+        assert_eq!([1, 2, 2, 3, 3, 3, 3, 42], bytes);
+
+        let mut bytes = [1u8, 2];
+        #[allow(clippy::type_complexity)]
+        let result: Option<Seq<T2<&mut [u8; 1], &mut [u8; 2]>, &mut [u8]>> =
+            bytes.as_mut_slice().try_into().ok();
+        assert_eq!(None, result, "slice is shorter");
+    }
+
     #[test]
     fn partition_ready_longer_slice_try_into() {
         let bytes = [1u8, 2, 2, 3, 3, 3, 3, 42];
@@ -521,6 +1476,12 @@ mod tests {
         assert_eq!(6, P3::<u8, 1, 2, 3>::SUM);
     }
 
+    #[test]
+    fn hlist_length() {
+        type L = HCons<u8, 1, HCons<u16, 2, HCons<[u8; 3], 3, HNil>>>;
+        assert_eq!(6, <L as ParamAndAssociatedConst<6>>::VALUE);
+    }
+
     #[test]
     fn wrappers_as_primitives() {
         #[derive(Debug, Clone, PartialEq, Eq)]
@@ -44,9 +44,54 @@ let _: [u16;3] = array; // let statements type coercioon
 assert_eq!([0x1100,0x3322,0x5544], array);
 ```
 
+- Array of any types implemented [FromLeBytesSlice] / [FromBeBytesSlice]
+```rust
+# use heterob::{P2, Seq, endianness::*};
+# use core::array::TryFromSliceError;
+let array: [P2<(u8, u16), 1, 2>; 2] = [0x00,0x11,0x22,0x33,0x44,0x55].le_bytes_into();
+assert_eq!([P2((0x00,0x2211)), P2((0x33,0x5544))], array);
+
+// User type implementing FromLeBytes<K> should implement FromLeBytesSlice to be array element
+#[derive(Debug, PartialEq, Eq)]
+struct Id(u16);
+impl FromLeBytes<2> for Id {
+    fn from_le_bytes(bytes: [u8; 2]) -> Self {
+        Self(bytes.le_bytes_into())
+    }
+}
+impl FromLeBytesSlice for Id {
+    const WIRE_SIZE: usize = 2;
+    fn from_le_bytes_slice(slice: &[u8]) -> Result<Seq<Self, &[u8]>, TryFromSliceError> {
+        TryFromLeBytes::try_from_le_bytes(slice)
+    }
+}
+let array: [Id; 2] = [0x00,0x11,0x22,0x33].le_bytes_into();
+assert_eq!([Id(0x1100), Id(0x3322)], array);
+```
+
 ## More than 26 entries conversion
 
-Library limited max to 26 types list conversion. There are several workarounds
+Tuple wrappers limited max to 26 types list conversion.
+Heterogeneous list [HCons] has no such limit, each node holds value and its length in bytes.
+Total length is still checked at compile time. Lists destructure to flat tuples with
+[IntoTuple](crate::IntoTuple).
+```rust
+# use heterob::{HCons, HNil, IntoTuple, endianness::*};
+let data = [
+    0x00, 0x11,0x11,0x11,0x11,
+    0x22, 0x33,0x33, 0x44,0x44,
+];
+
+type L = HCons<u8, 1, HCons<u32, 4, HCons<u8, 1, HCons<u16, 2, HCons<u16, 2, HNil>>>>>;
+let list: L = data.le_bytes_into();
+let (v0, v1, v2, v3, v4) = list.into_tuple();
+
+let sample = (0x00, 0x11111111, 0x22, 0x3333, 0x4444);
+
+assert_eq!(sample, (v0, v1, v2, v3, v4));
+```
+
+There are also several workarounds with tuple wrappers
 
 - Pre split large bytes array
 ```rust
@@ -85,6 +130,99 @@ let sample = (0x00, 0x11111111u32, 0x22, 0x3333u16, 0x4444u16);
 assert_eq!(sample, (v0, v1, v2, v3, v4));
 ```
 
+- Per field endianness override inside group wrapper
+```rust
+# use heterob::{T2, P3, endianness::*};
+let data = [0x00, 0x11,0x22,0x33,0x44, 0x55,0x66];
+
+let Le((byte, Be(dword), word)) = P3::<_, 1, 4, 2>(data).into();
+assert_eq!((0x00u8, 0x11223344u32, 0x6655u16), (byte, dword, word));
+
+let Be((Le(word), byte)) = T2::<[u8; 2], [u8; 1]>::from([0x11, 0x22, 0x33]).into();
+assert_eq!((0x2211u16, 0x33u8), (word, byte));
+```
+Wrappers don't implement opposite endianness conversion
+```compile_fail
+# use heterob::endianness::*;
+let _ = Be::<u32>::from_le_bytes([0x11, 0x22, 0x33, 0x44]);
+```
+
+- Slice parsing with endianness defined by wrapper
+```rust
+# use heterob::{Seq, endianness::*};
+let data = [0x00, 0x11,0x22, 0x33,0x44, 0x55].as_slice();
+
+let Seq { head: Le(byte), tail } = data.try_into().unwrap();
+assert_eq!(0x00u8, byte);
+
+let Seq { head: (Le(a), Be(b)), tail } = tail.try_into().unwrap();
+assert_eq!((0x2211u16, 0x3344u16), (a, b));
+assert_eq!(&[0x55], tail);
+```
+
+- Tuple parsing with widths inferred from element types
+```rust
+# use heterob::{Seq, endianness::*};
+let data = [0x00, 0x11,0x22, 0x33,0x44,0x55,0x66, 0x77];
+
+let Seq { head: Le((a, b, c)), tail } = data.as_slice().le_tuple_try_into().unwrap();
+assert_eq!((0x00u8, 0x2211u16, 0x66554433u32), (a, b, c));
+assert_eq!(&[0x77], tail);
+
+// For arrays the conversion is infallible, the total width is checked at compile time
+let Be((a, b, c, d)) = data.be_tuple_into();
+assert_eq!((0x00u8, 0x1122u16, 0x33445566u32, 0x77u8), (a, b, c, d));
+```
+Tuple that doesn't fit into array fails to compile
+```compile_fail
+# use heterob::{Seq, endianness::*};
+let Le((a, b)) = [0u8; 4].le_tuple_into();
+let _: (u16, u8) = (a, b);
+```
+
+- Storage types that keep bytes in wire order and convert on access
+```rust
+# use heterob::{P3, endianness::*};
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Header {
+    kind: u8,
+    length: U16Le,
+    id: U32Be,
+}
+
+let P3((kind, length, id)) = [0x01, 0x10, 0x00, 0x00, 0x00, 0x00, 0x2A].le_bytes_into();
+let mut header = Header { kind, length, id };
+assert_eq!(16, header.length.get());
+assert_eq!(header.id, 42);
+
+header.length.set(0x1234);
+assert_eq!([0x34, 0x12], header.length.to_bytes());
+assert_eq!(1, core::mem::align_of::<Header>());
+```
+
+- Const fn conversions for compile time tables
+```rust
+# use heterob::{P2, Seq, endianness::*};
+const DATA: [u8; 6] = [0x11, 0x22, 0x33, 0x44, 0x55, 0x66];
+
+// Bytes partition
+const HEADER: (u16, u32) = {
+    let (word, dword) = P2::<_, 2, 4>(DATA).split();
+    (u16::from_le_bytes(word), u32::from_be_bytes(dword))
+};
+assert_eq!((0x2211, 0x33445566), HEADER);
+
+// Slice head conversion
+const HEADER_BE: (u16, u32) = match be_u16(&DATA) {
+    Some(Seq { head: word, tail }) => match be_u32(tail) {
+        Some(Seq { head: dword, .. }) => (word, dword),
+        None => panic!("too short"),
+    },
+    None => panic!("too short"),
+};
+assert_eq!((0x1122, 0x33445566), HEADER_BE);
+```
+
 - Using endianness independent bytes to bytes conversion feature
 ```rust
 # use heterob::{T3, P3, endianness::*};
@@ -104,16 +242,26 @@ assert_eq!(sample, (v0, v1, v2, v3, v4));
 ```
 
 */
-use core::{mem::size_of, usize};
+use core::mem::size_of;
 use paste::paste;
 use core::array::TryFromSliceError;
 
 use super::*;
+use crate::layout::{Endian, Field, Layout};
+
+#[cfg(feature = "derive")]
+pub use heterob_derive::{FromBeBytes, FromLeBytes};
 
 /// Little endian bytes to value conversion
 ///
 /// It is the reciprocal of [LeBytesInto].
+///
+/// [ASSERT_SELF_SIZE](FromLeBytes::ASSERT_SELF_SIZE) compares `N` with size of value in memory,
+/// so it fails for padded types. Number of bytes on wire is
+/// [FromLeBytesSlice::WIRE_SIZE], [FromLeBytesSlice::from_le_bytes_slice] is the API for
+/// padded types.
 pub trait FromLeBytes<const N: usize>: Sized {
+    /// Size of value in memory, may include padding
     const SIZE: usize = size_of::<Self>();
     const ASSERT_SELF_SIZE: (usize, usize) = (Self::SIZE - N, N - Self::SIZE);
     fn from_le_bytes(bytes: [u8;N]) -> Self;
@@ -163,16 +311,27 @@ impl<T: FromLeBytes<N>, const N: usize> From<[u8;N]> for Le<T> {
     }
 }
 
+/// Spans of wrapped tuple elements
+impl<T: WithSpans<N>, const N: usize> WithSpans<N> for Le<T> {
+    type Output = Le<T::Output>;
+    fn with_spans(self, spans: [core::ops::Range<usize>; N]) -> Self::Output {
+        Le(self.0.with_spans(spans))
+    }
+}
+
 /// Little endian bytes slice to value conversion that may fail
 ///
 /// It is the reciprocal of [LeBytesTryInto].
+///
+/// Types implementing [FromLeBytes] for several `N` (like partitions) require explicit `N`.
+/// [FromLeBytesSlice::from_le_bytes_slice] splits slice by wire size of type without it.
 pub trait TryFromLeBytes<const N: usize>: Sized + FromLeBytes<N> {
     fn try_from_le_bytes(slice: &[u8]) -> Result<Seq<Self, &[u8]>, TryFromSliceError>;
 }
 
 impl<T: FromLeBytes<N>, const N: usize> TryFromLeBytes<N> for T {
     fn try_from_le_bytes(slice: &[u8]) -> Result<Seq<Self, &[u8]>, TryFromSliceError> {
-        let (head, tail) = slice.split_at(slice.len().min(size_of::<Self>()));
+        let (head, tail) = slice.split_at(slice.len().min(N));
         let head = head.try_into().map(FromLeBytes::from_le_bytes)?;
         Ok(Seq { head, tail })
     }
@@ -181,18 +340,57 @@ impl<T: FromLeBytes<N>, const N: usize> TryFromLeBytes<N> for T {
 /// Big endian bytes slice to value conversion that may fail
 ///
 /// It is the reciprocal of [BeBytesTryInto].
+///
+/// Types implementing [FromBeBytes] for several `N` (like partitions) require explicit `N`.
+/// [FromBeBytesSlice::from_be_bytes_slice] splits slice by wire size of type without it.
 pub trait TryFromBeBytes<const N: usize>: Sized + FromBeBytes<N> {
     fn try_from_be_bytes(slice: &[u8]) -> Result<Seq<Self, &[u8]>, TryFromSliceError>;
 }
 
 impl<T: FromBeBytes<N>, const N: usize> TryFromBeBytes<N> for T {
     fn try_from_be_bytes(slice: &[u8]) -> Result<Seq<Self, &[u8]>, TryFromSliceError> {
-        let (head, tail) = slice.split_at(slice.len().min(size_of::<Self>()));
+        let (head, tail) = slice.split_at(slice.len().min(N));
         let head = head.try_into().map(FromBeBytes::from_be_bytes)?;
         Ok(Seq { head, tail })
     }
 }
 
+/// Little endian bytes slice to value conversion with wire size defined by type
+///
+/// In contrast with [TryFromLeBytes] there is no const generic parameter, the number of
+/// consumed bytes is always known from the type. Types implemented this trait could be decoded
+/// as array elements. It is not implemented for [u8], so bytes arrays keep dedicated
+/// bytes to bytes (no)conversion, single bytes are decoded with [Le] / [Be] wrappers.
+///
+/// Arrays are not decoded from any `FromLeBytes<K>` element directly: `K` in
+/// `impl<T: FromLeBytes<K>, const K: usize> FromLeBytes<N> for [T; M]` is not constrained by
+/// the array type (E0207), as one type may implement [FromLeBytes] for several `K`. Every library
+/// type implementing [FromLeBytes] implements this trait too, user types should implement it
+/// with [TryFromLeBytes::try_from_le_bytes].
+pub trait FromLeBytesSlice: Sized {
+    /// Number of consumed bytes
+    const WIRE_SIZE: usize;
+    fn from_le_bytes_slice(slice: &[u8]) -> Result<Seq<Self, &[u8]>, TryFromSliceError>;
+}
+
+/// Big endian bytes slice to value conversion with wire size defined by type
+///
+/// In contrast with [TryFromBeBytes] there is no const generic parameter, the number of
+/// consumed bytes is always known from the type. Types implemented this trait could be decoded
+/// as array elements. It is not implemented for [u8], so bytes arrays keep dedicated
+/// bytes to bytes (no)conversion, single bytes are decoded with [Le] / [Be] wrappers.
+///
+/// Arrays are not decoded from any `FromBeBytes<K>` element directly: `K` in
+/// `impl<T: FromBeBytes<K>, const K: usize> FromBeBytes<N> for [T; M]` is not constrained by
+/// the array type (E0207), as one type may implement [FromBeBytes] for several `K`. Every library
+/// type implementing [FromBeBytes] implements this trait too, user types should implement it
+/// with [TryFromBeBytes::try_from_be_bytes].
+pub trait FromBeBytesSlice: Sized {
+    /// Number of consumed bytes
+    const WIRE_SIZE: usize;
+    fn from_be_bytes_slice(slice: &[u8]) -> Result<Seq<Self, &[u8]>, TryFromSliceError>;
+}
+
 /// Little endian bytes slice to value attempted conversion
 ///
 /// The opposite of [TryFromLeBytes].
@@ -229,10 +427,50 @@ where
     }
 }
 
+/// Little endian bytes to tuple attempted conversion with widths inferred from element types
+///
+/// Each element width is a [FromLeBytesSlice::WIRE_SIZE] of [Le] wrapped element type.
+pub trait LeTupleTryInto<'a, T> {
+    /// Performs the conversion.
+    fn le_tuple_try_into(self) -> Result<Seq<Le<T>, &'a [u8]>, TryFromSliceError>;
+}
+
+/// Little endian bytes array to tuple conversion with widths inferred from element types
+///
+/// Each element width is a [FromLeBytesSlice::WIRE_SIZE] of [Le] wrapped element type.
+/// The total width is checked at compile time.
+pub trait LeTupleInto<T> {
+    /// Performs the conversion.
+    fn le_tuple_into(self) -> Le<T>;
+}
+
+/// Big endian bytes to tuple attempted conversion with widths inferred from element types
+///
+/// Each element width is a [FromBeBytesSlice::WIRE_SIZE] of [Be] wrapped element type.
+pub trait BeTupleTryInto<'a, T> {
+    /// Performs the conversion.
+    fn be_tuple_try_into(self) -> Result<Seq<Be<T>, &'a [u8]>, TryFromSliceError>;
+}
+
+/// Big endian bytes array to tuple conversion with widths inferred from element types
+///
+/// Each element width is a [FromBeBytesSlice::WIRE_SIZE] of [Be] wrapped element type.
+/// The total width is checked at compile time.
+pub trait BeTupleInto<T> {
+    /// Performs the conversion.
+    fn be_tuple_into(self) -> Be<T>;
+}
+
 /// Big endian bytes to value conversion
 ///
 /// It is the reciprocal of [BeBytesInto].
+///
+/// [ASSERT_SELF_SIZE](FromBeBytes::ASSERT_SELF_SIZE) compares `N` with size of value in memory,
+/// so it fails for padded types. Number of bytes on wire is
+/// [FromBeBytesSlice::WIRE_SIZE], [FromBeBytesSlice::from_be_bytes_slice] is the API for
+/// padded types.
 pub trait FromBeBytes<const N: usize>: Sized {
+    /// Size of value in memory, may include padding
     const SIZE: usize = size_of::<Self>();
     const ASSERT_SELF_SIZE: (usize, usize) = (Self::SIZE - N, N - Self::SIZE);
     fn from_be_bytes(bytes: [u8;N]) -> Self;
@@ -282,6 +520,93 @@ impl<T: FromBeBytes<N>, const N: usize> From<[u8;N]> for Be<T> {
     }
 }
 
+/// Spans of wrapped tuple elements
+impl<T: WithSpans<N>, const N: usize> WithSpans<N> for Be<T> {
+    type Output = Be<T::Output>;
+    fn with_spans(self, spans: [core::ops::Range<usize>; N]) -> Self::Output {
+        Be(self.0.with_spans(spans))
+    }
+}
+
+macro_rules! endianness_element {
+    ($outer:ident: $($inner:ident),+) => { paste!{
+        #[doc=concat!(stringify!($outer), " bytes to [", stringify!($outer), "] group wrapper element conversion")]
+        ///
+        #[doc=concat!("Implemented for every [From", stringify!($outer), "Bytes] type and for [Le] / [Be]")]
+        /// wrapped types, so inner wrapper endianness wins over outer one.
+        pub trait [<From $outer Element>]<const N: usize>: Sized {
+            fn [<from_ $outer:lower _element>](bytes: [u8;N]) -> Self;
+        }
+
+        impl<T: [<From $outer Bytes>]<N>, const N: usize> [<From $outer Element>]<N> for T {
+            fn [<from_ $outer:lower _element>](bytes: [u8;N]) -> Self {
+                T::[<from_ $outer:lower _bytes>](bytes)
+            }
+        }
+        $(
+            /*
+            impl<T: FromBeBytes<N>, const N: usize> FromLeElement<N> for Be<T> {
+                fn from_le_element(bytes: [u8;N]) -> Self {
+                    Be(T::from_be_bytes(bytes))
+                }
+            }
+            */
+            impl<T: [<From $inner Bytes>]<N>, const N: usize> [<From $outer Element>]<N> for $inner<T> {
+                fn [<from_ $outer:lower _element>](bytes: [u8;N]) -> Self {
+                    $inner(T::[<from_ $inner:lower _bytes>](bytes))
+                }
+            }
+        )+
+    }};
+}
+
+endianness_element!(Le: Le, Be);
+endianness_element!(Be: Le, Be);
+
+/// Bytes slice to value conversion with endianness defined by [Le] / [Be] wrapper
+///
+/// It is implemented for [Le] / [Be] only and dispatches to [FromLeBytesSlice] /
+/// [FromBeBytesSlice]. It backs `TryFrom<&[u8]>` for [Seq] of tuples of wrapped values.
+pub trait FromWrappedSlice: Sized {
+    fn from_wrapped_slice(slice: &[u8]) -> Result<Seq<Self, &[u8]>, TryFromSliceError>;
+}
+
+macro_rules! endianness_wrapped {
+    ($($e:ident),+) => { paste!{ $(
+        impl<T> FromWrappedSlice for $e<T>
+        where
+            Self: [<From $e BytesSlice>],
+        {
+            fn from_wrapped_slice(slice: &[u8]) -> Result<Seq<Self, &[u8]>, TryFromSliceError> {
+                $e::<T>::[<from_ $e:lower _bytes_slice>](slice)
+            }
+        }
+
+        /*
+        impl<'a, T> TryFrom<&'a [u8]> for Seq<Le<T>, &'a [u8]>
+        where
+            Le<T>: FromLeBytesSlice,
+        {
+            type Error = TryFromSliceError;
+            fn try_from(slice: &'a [u8]) -> Result<Self, Self::Error> {
+                Le::<T>::from_le_bytes_slice(slice)
+            }
+        }
+        */
+        impl<'a, T> TryFrom<&'a [u8]> for Seq<$e<T>, &'a [u8]>
+        where
+            $e<T>: [<From $e BytesSlice>],
+        {
+            type Error = TryFromSliceError;
+            fn try_from(slice: &'a [u8]) -> Result<Self, Self::Error> {
+                $e::<T>::[<from_ $e:lower _bytes_slice>](slice)
+            }
+        }
+    )+ }};
+}
+
+endianness_wrapped!(Le, Be);
+
 macro_rules! endianness_integers {
     (Common: $e:ident => $($t:ty),+ $(,)?) => { paste!{ $(
         /*
@@ -298,36 +623,17 @@ macro_rules! endianness_integers {
         }
 
         /*
-        impl<const M: usize, const N: usize> FromLeBytes<M> for [u16; N] {
-            fn from_le_bytes(bytes: [u8; M]) -> Self {
-                #![allow(path_statements)]
-                <Self as [<From $e Bytes>]<N>>::ASSERT_SELF_SIZE;
-
-                let mut result = [0; N];
-                for (n, data) in bytes.chunks_exact(size_of::<u16>()).enumerate() {
-                    match <[u8;size_of::<u16>()]>::try_from(data) {
-                        Ok(data) => result[n] = data.le_bytes_into(),
-                        Err(_) => break,
-                    }
-                }
-                result
+        impl FromLeBytesSlice for u16 {
+            const WIRE_SIZE: usize = size_of::<u16>();
+            fn from_le_bytes_slice(slice: &[u8]) -> Result<Seq<Self, &[u8]>, TryFromSliceError> {
+                TryFromLeBytes::try_from_le_bytes(slice)
             }
         }
         */
-        impl<const N: usize, const M: usize> [<From $e Bytes>]<N> for [$t;M] {
-            fn [<from_ $e:lower _bytes>](bytes: [u8;N]) -> Self {
-                #![allow(path_statements)]
-                <Self as [<From $e Bytes>]<N>>::ASSERT_SELF_SIZE;
-
-                const SIZE: usize = size_of::<$t>();
-                let mut result = [0;M];
-                for (n, data) in bytes.chunks_exact(SIZE).enumerate() {
-                    match <[u8;SIZE]>::try_from(data) {
-                        Ok(data) => result[n] = data.[<$e:lower _bytes_into>](),
-                        Err(_) => break,
-                    }
-                }
-                result
+        impl [<From $e BytesSlice>] for $t {
+            const WIRE_SIZE: usize = size_of::<$t>();
+            fn [<from_ $e:lower _bytes_slice>](slice: &[u8]) -> Result<Seq<Self, &[u8]>, TryFromSliceError> {
+                [<TryFrom $e Bytes>]::[<try_from_ $e:lower _bytes>](slice)
             }
         }
     )+ }};
@@ -345,6 +651,45 @@ macro_rules! endianness_integers {
 
 endianness_integers!(u16,u32,u64,u128,usize);
 
+macro_rules! endianness_const {
+    ($($e:ident),+ => $ty:ty) => { paste!{ $(
+        /*
+        pub const fn le_u16(slice: &[u8]) -> Option<Seq<u16, &[u8]>> {
+            if slice.len() < size_of::<u16>() {
+                return None;
+            }
+            let (head, tail) = slice.split_at(size_of::<u16>());
+            let mut bytes = [0; size_of::<u16>()];
+            let mut i = 0;
+            while i < bytes.len() {
+                bytes[i] = head[i];
+                i += 1;
+            }
+            Some(Seq { head: u16::from_le_bytes(bytes), tail })
+        }
+        */
+        #[doc=concat!("Const fn conversion of slice head to ", stringify!($e), " [", stringify!($ty), "]")]
+        pub const fn [<$e:lower _ $ty>](slice: &[u8]) -> Option<Seq<$ty, &[u8]>> {
+            if slice.len() < size_of::<$ty>() {
+                return None;
+            }
+            let (head, tail) = slice.split_at(size_of::<$ty>());
+            let mut bytes = [0; size_of::<$ty>()];
+            let mut i = 0;
+            while i < bytes.len() {
+                bytes[i] = head[i];
+                i += 1;
+            }
+            Some(Seq { head: $ty::[<from_ $e:lower _bytes>](bytes), tail })
+        }
+    )+ }};
+    ($($ty:ty),+ $(,)?) => { $(
+        endianness_const!(Le, Be => $ty);
+    )+ };
+}
+
+endianness_const!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
 
 macro_rules! endianness_alphabet {
     (Common: $e:ident => $len:literal: $($cl:ident),+ $(,)?) => { paste!{
@@ -362,50 +707,48 @@ macro_rules! endianness_alphabet {
         }
 
         /*
-        impl<TY,A,B,C, const AN: usize, const BN: usize, const CN: usize>
-            From<T3<[TY;AN],[TY;BN],[TY;CN]>> for Le<(A,B,C)>
+        impl<A,B,C, const AN: usize, const BN: usize, const CN: usize>
+            From<T3<[u8;AN],[u8;BN],[u8;CN]>> for Le<(A,B,C)>
         where
-            Le<A>: From<[TY;AN]>,
-            Le<B>: From<[TY;BN]>,
-            Le<C>: From<[TY;CN]>,
+            A: FromLeElement<AN>,
+            B: FromLeElement<BN>,
+            C: FromLeElement<CN>,
         {
-            fn from(data: T3<[TY;AN],[TY;BN],[TY;CN]>) -> Self {
-                <(Le<A>,Le<B>,Le<C>)>::from(data).into()
+            fn from(T3(a,b,c): T3<[u8;AN],[u8;BN],[u8;CN]>) -> Self {
+                Le((A::from_le_element(a),B::from_le_element(b),C::from_le_element(c)))
             }
         }
         */
-        impl<TY, $($cl,)+ $(const [<$cl N>]: usize,)+>
-            From<[<T $len>]<$([TY;[<$cl N>]],)+>> for $e<($($cl,)+)>
+        impl<$($cl,)+ $(const [<$cl N>]: usize,)+>
+            From<[<T $len>]<$([u8;[<$cl N>]],)+>> for $e<($($cl,)+)>
         where
-            $($e<$cl>: From<[TY;[<$cl N>]]>,)+
+            $($cl: [<From $e Element>]<[<$cl N>]>,)+
         {
-            fn from(data: [<T $len>]<$([TY;[<$cl N>]],)+>) -> Self {
-                <($($e<$cl>,)+)>::from(data).into()
+            fn from([<T $len>]($([<$cl:lower>],)+): [<T $len>]<$([u8;[<$cl N>]],)+>) -> Self {
+                $e(($($cl::[<from_ $e:lower _element>]([<$cl:lower>]),)+))
             }
         }
 
         /*
-        impl<TY,A,B,C, const N: usize, const AN: usize, const BN: usize, const CN: usize>
-            From<P3<[TY;N],AN,BN,CN>> for Le<(A,B,C)>
+        impl<A,B,C, const N: usize, const AN: usize, const BN: usize, const CN: usize>
+            From<P3<[u8;N],AN,BN,CN>> for Le<(A,B,C)>
         where
-            TY: Copy + Default,
-            Le<A>: From<[TY;AN]>,
-            Le<B>: From<[TY;BN]>,
-            Le<C>: From<[TY;CN]>,
+            A: FromLeElement<AN>,
+            B: FromLeElement<BN>,
+            C: FromLeElement<CN>,
         {
-            fn from(data: P3<[TY;N],AN,BN,CN>) -> Self {
-                <(Le<A>,Le<B>,Le<C>)>::from(data).into()
+            fn from(P3(data): P3<[u8;N],AN,BN,CN>) -> Self {
+                T3::<[u8;AN],[u8;BN],[u8;CN]>::from(data).into()
             }
         }
         */
-        impl<TY, $($cl,)+ const NU: usize, $(const [<$cl N>]: usize,)+>
-            From<[<P $len>]<[TY;NU],$([<$cl N>],)+>> for $e<($($cl,)+)>
+        impl<$($cl,)+ const NU: usize, $(const [<$cl N>]: usize,)+>
+            From<[<P $len>]<[u8;NU],$([<$cl N>],)+>> for $e<($($cl,)+)>
         where
-            TY: Copy + Default,
-            $($e<$cl>: From<[TY;[<$cl N>]]>,)+
+            $($cl: [<From $e Element>]<[<$cl N>]>,)+
         {
-            fn from(data: [<P $len>]<[TY;NU],$([<$cl N>],)+>) -> Self {
-                <($($e<$cl>,)+)>::from(data).into()
+            fn from([<P $len>](data): [<P $len>]<[u8;NU],$([<$cl N>],)+>) -> Self {
+                [<T $len>]::<$([u8;[<$cl N>]],)+>::from(data).into()
             }
         }
 
@@ -433,6 +776,166 @@ macro_rules! endianness_alphabet {
                 [<P $len>](($([<$cl:lower>].[<$e:lower _bytes_into>](),)+))
             }
         }
+
+        /*
+        impl<TY: AsRef<[u8]>, const AN: usize, const BN: usize, const CN: usize> P3<TY, AN, BN, CN> {
+            pub fn write_le_dump<W: core::fmt::Write>(&self, w: &mut W) -> core::fmt::Result {
+                let field = |n| Field { endian: Endian::Le, ..Field::bytes(n) };
+                Layout(Self::WIDTHS.map(field)).write_bytes_dump(w, self.0.as_ref())
+            }
+        }
+        */
+        impl<TY: AsRef<[u8]>, $(const [<$cl N>]: usize,)+> [<P $len>]<TY, $([<$cl N>],)+> {
+            /// Writes hexdump with field boundaries and decoded field values
+            pub fn [<write_ $e:lower _dump>]<W: core::fmt::Write>(&self, w: &mut W) -> core::fmt::Result {
+                let field = |n| Field { endian: Endian::$e, ..Field::bytes(n) };
+                Layout(Self::WIDTHS.map(field)).write_bytes_dump(w, self.0.as_ref())
+            }
+        }
+
+        /*
+        impl<A,B,C, const AN: usize, const BN: usize, const CN: usize>
+            FromLeBytesSlice for P3<(A,B,C), AN, BN, CN>
+        where
+            A: FromLeBytes<AN>,
+            B: FromLeBytes<BN>,
+            C: FromLeBytes<CN>,
+        {
+            const WIRE_SIZE: usize = Self::SUM;
+            fn from_le_bytes_slice(slice: &[u8]) -> Result<Seq<Self, &[u8]>, TryFromSliceError> {
+                let Seq { head: T3(a,b,c), tail }: Seq<T3<[u8; AN], [u8; BN], [u8; CN]>, _> =
+                    slice.try_into()?;
+                let head = P3((a.le_bytes_into(),b.le_bytes_into(),c.le_bytes_into()));
+                Ok(Seq { head, tail })
+            }
+        }
+        */
+        impl<$($cl,)+ $(const [<$cl N>]: usize,)+>
+            [<From $e BytesSlice>] for [<P $len>]<($($cl,)+), $([<$cl N>],)+>
+        where
+            $( $cl: [<From $e Bytes>]<[<$cl N>]>, )+
+        {
+            const WIRE_SIZE: usize = Self::SUM;
+            fn [<from_ $e:lower _bytes_slice>](slice: &[u8]) -> Result<Seq<Self, &[u8]>, TryFromSliceError> {
+                let Seq { head: [<T $len>]($([<$cl:lower>],)+), tail }:
+                    Seq<[<T $len>]<$([u8; [<$cl N>]],)+>, _> = slice.try_into()?;
+                let head = [<P $len>](($([<$cl:lower>].[<$e:lower _bytes_into>](),)+));
+                Ok(Seq { head, tail })
+            }
+        }
+    }};
+    (Tuple: $e:ident => $len:literal: $($cl:ident),+ $(,)?) => { paste!{
+        /*
+        impl<A, B, C, const N: usize> ParamAndAssociatedConst<N> for Le<(A, B, C)>
+        where
+            Le<A>: FromLeBytesSlice,
+            Le<B>: FromLeBytesSlice,
+            Le<C>: FromLeBytesSlice,
+        {
+            const VALUE: usize = 0
+                + <Le<A> as FromLeBytesSlice>::WIRE_SIZE
+                + <Le<B> as FromLeBytesSlice>::WIRE_SIZE
+                + <Le<C> as FromLeBytesSlice>::WIRE_SIZE;
+        }
+        */
+        impl<$($cl,)+ const NU: usize> ParamAndAssociatedConst<NU> for $e<($($cl,)+)>
+        where
+            $($e<$cl>: [<From $e BytesSlice>],)+
+        {
+            const VALUE: usize = 0 $(+ <$e<$cl> as [<From $e BytesSlice>]>::WIRE_SIZE)+;
+        }
+
+        /*
+        impl<'a, A, B, C> LeTupleTryInto<'a, (A, B, C)> for &'a [u8]
+        where
+            Le<A>: FromLeBytesSlice,
+            Le<B>: FromLeBytesSlice,
+            Le<C>: FromLeBytesSlice,
+        {
+            fn le_tuple_try_into(self) -> Result<Seq<Le<(A, B, C)>, &'a [u8]>, TryFromSliceError> {
+                let Seq { head: Le(a), tail } = Le::<A>::from_le_bytes_slice(self)?;
+                let Seq { head: Le(b), tail } = Le::<B>::from_le_bytes_slice(tail)?;
+                let Seq { head: Le(c), tail } = Le::<C>::from_le_bytes_slice(tail)?;
+                Ok(Seq { head: Le((a, b, c)), tail })
+            }
+        }
+        */
+        impl<'a, $($cl,)+> [<$e TupleTryInto>]<'a, ($($cl,)+)> for &'a [u8]
+        where
+            $($e<$cl>: [<From $e BytesSlice>],)+
+        {
+            fn [<$e:lower _tuple_try_into>](self) ->
+                Result<Seq<$e<($($cl,)+)>, &'a [u8]>, TryFromSliceError>
+            {
+                let tail = self;
+                $(
+                    let Seq { head: $e([<$cl:lower>]), tail } =
+                        $e::<$cl>::[<from_ $e:lower _bytes_slice>](tail)?;
+                )+
+                Ok(Seq { head: $e(($([<$cl:lower>],)+)), tail })
+            }
+        }
+
+        /*
+        impl<A, B, C, const N: usize> LeTupleInto<(A, B, C)> for [u8; N]
+        where
+            Le<A>: FromLeBytesSlice,
+            Le<B>: FromLeBytesSlice,
+            Le<C>: FromLeBytesSlice,
+        {
+            fn le_tuple_into(self) -> Le<(A, B, C)> {
+                #![allow(path_statements)]
+                <Le<(A, B, C)> as ParamAndAssociatedConst<N>>::ASSERT_EQ;
+                match self.as_slice().le_tuple_try_into() {
+                    Ok(Seq { head, .. }) => head,
+                    Err(_) => unreachable!("tuple width is checked at compile time"),
+                }
+            }
+        }
+        */
+        impl<$($cl,)+ const NU: usize> [<$e TupleInto>]<($($cl,)+)> for [u8; NU]
+        where
+            $($e<$cl>: [<From $e BytesSlice>],)+
+        {
+            fn [<$e:lower _tuple_into>](self) -> $e<($($cl,)+)> {
+                #![allow(path_statements)]
+                <$e<($($cl,)+)> as ParamAndAssociatedConst<NU>>::ASSERT_EQ;
+                match self.as_slice().[<$e:lower _tuple_try_into>]() {
+                    Ok(Seq { head, .. }) => head,
+                    Err(_) => unreachable!("tuple width is checked at compile time"),
+                }
+            }
+        }
+    }};
+    (Wrapped => $len:literal: $($cl:ident),+ $(,)?) => { paste!{
+        /*
+        impl<'a, A, B, C> TryFrom<&'a [u8]> for Seq<(A, B, C), &'a [u8]>
+        where
+            A: FromWrappedSlice,
+            B: FromWrappedSlice,
+            C: FromWrappedSlice,
+        {
+            type Error = TryFromSliceError;
+            fn try_from(slice: &'a [u8]) -> Result<Self, Self::Error> {
+                let Seq { head: a, tail: slice } = A::from_wrapped_slice(slice)?;
+                let Seq { head: b, tail: slice } = B::from_wrapped_slice(slice)?;
+                let Seq { head: c, tail: slice } = C::from_wrapped_slice(slice)?;
+                Ok(Seq { head: (a, b, c), tail: slice })
+            }
+        }
+        */
+        impl<'a, $($cl,)+> TryFrom<&'a [u8]> for Seq<($($cl,)+), &'a [u8]>
+        where
+            $($cl: FromWrappedSlice,)+
+        {
+            type Error = TryFromSliceError;
+            fn try_from(slice: &'a [u8]) -> Result<Self, Self::Error> {
+                $(
+                    let Seq { head: [<$cl:lower>], tail: slice } = $cl::from_wrapped_slice(slice)?;
+                )+
+                Ok(Seq { head: ($([<$cl:lower>],)+), tail: slice })
+            }
+        }
     }};
     (Le => $len:literal: $($cl:ident),+ $(,)?) => { paste!{
     }};
@@ -443,6 +946,9 @@ macro_rules! endianness_alphabet {
         // endianness_alphabet!(Le => $len: $($cl),+);
         endianness_alphabet!(Common: Be => $len: $($cl),+);
         // endianness_alphabet!(Be => $len: $($cl),+);
+        endianness_alphabet!(Wrapped => $len: $($cl),+);
+        endianness_alphabet!(Tuple: Le => $len: $($cl),+);
+        endianness_alphabet!(Tuple: Be => $len: $($cl),+);
     };
 }
 
@@ -473,6 +979,190 @@ endianness_alphabet!(24: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T,U,V,W,X);
 endianness_alphabet!(25: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T,U,V,W,X,Y);
 endianness_alphabet!(26: A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T,U,V,W,X,Y,Z);
 
+macro_rules! endianness_slice {
+    ($($e:ident),+) => { paste!{ $(
+        /*
+        impl<T: FromLeBytesSlice> FromLeBytesSlice for [T; M] {
+            const WIRE_SIZE: usize = T::WIRE_SIZE * M;
+            fn from_le_bytes_slice(slice: &[u8]) -> Result<Seq<Self, &[u8]>, TryFromSliceError> {
+                let mut tail = slice;
+                let head = try_array_from_fn(|_| -> Result<T, TryFromSliceError> {
+                    let Seq { head, tail: rest } = T::from_le_bytes_slice(tail)?;
+                    tail = rest;
+                    Ok(head)
+                })?;
+                Ok(Seq { head, tail })
+            }
+        }
+        */
+        impl<T: [<From $e BytesSlice>], const M: usize> [<From $e BytesSlice>] for [T; M] {
+            const WIRE_SIZE: usize = <T as [<From $e BytesSlice>]>::WIRE_SIZE * M;
+            fn [<from_ $e:lower _bytes_slice>](slice: &[u8]) -> Result<Seq<Self, &[u8]>, TryFromSliceError> {
+                let mut tail = slice;
+                let head = try_array_from_fn(|_| -> Result<T, TryFromSliceError> {
+                    let Seq { head, tail: rest } = T::[<from_ $e:lower _bytes_slice>](tail)?;
+                    tail = rest;
+                    Ok(head)
+                })?;
+                Ok(Seq { head, tail })
+            }
+        }
+
+        /// Bytes to bytes (no)conversion
+        impl<const N: usize> [<From $e BytesSlice>] for [u8; N] {
+            const WIRE_SIZE: usize = N;
+            fn [<from_ $e:lower _bytes_slice>](slice: &[u8]) -> Result<Seq<Self, &[u8]>, TryFromSliceError> {
+                slice.try_into()
+            }
+        }
+
+        /*
+        impl<T: FromLeBytesSlice, const N: usize, const M: usize> FromLeBytes<N> for [T; M] {
+            const ASSERT_SELF_SIZE: (usize, usize) = (
+                <Self as FromLeBytesSlice>::WIRE_SIZE - N,
+                N - <Self as FromLeBytesSlice>::WIRE_SIZE,
+            );
+            fn from_le_bytes(bytes: [u8; N]) -> Self {
+                #![allow(path_statements)]
+                <Self as FromLeBytes<N>>::ASSERT_SELF_SIZE;
+
+                match Self::from_le_bytes_slice(&bytes) {
+                    Ok(Seq { head, .. }) => head,
+                    Err(_) => unreachable!("array length is checked at compile time"),
+                }
+            }
+        }
+        */
+        impl<T, const N: usize, const M: usize> [<From $e Bytes>]<N> for [T; M]
+        where
+            T: [<From $e BytesSlice>],
+        {
+            const ASSERT_SELF_SIZE: (usize, usize) = (
+                <Self as [<From $e BytesSlice>]>::WIRE_SIZE - N,
+                N - <Self as [<From $e BytesSlice>]>::WIRE_SIZE,
+            );
+            fn [<from_ $e:lower _bytes>](bytes: [u8; N]) -> Self {
+                #![allow(path_statements)]
+                <Self as [<From $e Bytes>]<N>>::ASSERT_SELF_SIZE;
+
+                match Self::[<from_ $e:lower _bytes_slice>](&bytes) {
+                    Ok(Seq { head, .. }) => head,
+                    Err(_) => unreachable!("array length is checked at compile time"),
+                }
+            }
+        }
+
+        impl<T: [<From $e BytesSlice>]> [<From $e BytesSlice>] for $e<T> {
+            const WIRE_SIZE: usize = <T as [<From $e BytesSlice>]>::WIRE_SIZE;
+            fn [<from_ $e:lower _bytes_slice>](slice: &[u8]) -> Result<Seq<Self, &[u8]>, TryFromSliceError> {
+                let Seq { head, tail } = T::[<from_ $e:lower _bytes_slice>](slice)?;
+                Ok(Seq { head: $e(head), tail })
+            }
+        }
+
+        /// One byte slice conversion
+        impl [<From $e BytesSlice>] for $e<u8> {
+            const WIRE_SIZE: usize = 1;
+            fn [<from_ $e:lower _bytes_slice>](slice: &[u8]) -> Result<Seq<Self, &[u8]>, TryFromSliceError> {
+                let Seq { head: [byte], tail } = slice.try_into()?;
+                Ok(Seq { head: $e(byte), tail })
+            }
+        }
+
+        impl [<From $e BytesSlice>] for HNil {
+            const WIRE_SIZE: usize = 0;
+            fn [<from_ $e:lower _bytes_slice>](slice: &[u8]) -> Result<Seq<Self, &[u8]>, TryFromSliceError> {
+                Ok(Seq { head: HNil, tail: slice })
+            }
+        }
+
+        /*
+        impl<H, T, const HN: usize> FromLeBytesSlice for HCons<H, HN, T>
+        where
+            H: FromLeBytes<HN>,
+            T: FromLeBytesSlice,
+        {
+            const WIRE_SIZE: usize = HN + T::WIRE_SIZE;
+            fn from_le_bytes_slice(slice: &[u8]) -> Result<Seq<Self, &[u8]>, TryFromSliceError> {
+                let Seq { head, tail }: Seq<[u8; HN], _> = slice.try_into()?;
+                let Seq { head: list, tail } = T::from_le_bytes_slice(tail)?;
+                Ok(Seq { head: HCons(head.le_bytes_into(), list), tail })
+            }
+        }
+        */
+        impl<H, T, const HN: usize> [<From $e BytesSlice>] for HCons<H, HN, T>
+        where
+            H: [<From $e Bytes>]<HN>,
+            T: [<From $e BytesSlice>],
+        {
+            const WIRE_SIZE: usize = HN + <T as [<From $e BytesSlice>]>::WIRE_SIZE;
+            fn [<from_ $e:lower _bytes_slice>](slice: &[u8]) -> Result<Seq<Self, &[u8]>, TryFromSliceError> {
+                let Seq { head, tail }: Seq<[u8; HN], _> = slice.try_into()?;
+                let Seq { head: list, tail } = T::[<from_ $e:lower _bytes_slice>](tail)?;
+                Ok(Seq { head: HCons(head.[<$e:lower _bytes_into>](), list), tail })
+            }
+        }
+
+        /*
+        impl<H, T, const N: usize, const HN: usize> FromLeBytes<N> for HCons<H, HN, T>
+        where
+            Self: FromLeBytesSlice + ParamAndAssociatedConst<N>,
+        {
+            fn from_le_bytes(bytes: [u8; N]) -> Self {
+                #![allow(path_statements)]
+                <Self as ParamAndAssociatedConst<N>>::ASSERT_EQ;
+
+                match Self::from_le_bytes_slice(&bytes) {
+                    Ok(Seq { head, .. }) => head,
+                    Err(_) => unreachable!("list length is checked at compile time"),
+                }
+            }
+        }
+        */
+        impl<H, T, const N: usize, const HN: usize> [<From $e Bytes>]<N> for HCons<H, HN, T>
+        where
+            Self: [<From $e BytesSlice>] + ParamAndAssociatedConst<N>,
+        {
+            fn [<from_ $e:lower _bytes>](bytes: [u8; N]) -> Self {
+                #![allow(path_statements)]
+                <Self as ParamAndAssociatedConst<N>>::ASSERT_EQ;
+
+                match Self::[<from_ $e:lower _bytes_slice>](&bytes) {
+                    Ok(Seq { head, .. }) => head,
+                    Err(_) => unreachable!("list length is checked at compile time"),
+                }
+            }
+        }
+    )+ }};
+}
+
+endianness_slice!(Le, Be);
+
+macro_rules! endianness_hlist {
+    ($($e:ident),+) => { paste!{ $(
+        #[doc=concat!(stringify!($e), " bytes slice to heterogeneous list conversion that may fail")]
+        ///
+        #[doc=concat!("Implemented for [HCons] lists of [From", stringify!($e), "Bytes] elements")]
+        #[doc=concat!("on top of [From", stringify!($e), "BytesSlice].")]
+        #[doc=concat!("Lists also implement [From", stringify!($e), "Bytes] with list length")]
+        /// checked at compile time.
+        pub trait [<From $e BytesHList>]: [<From $e BytesSlice>] {
+            fn [<from_ $e:lower _bytes_hlist>](slice: &[u8]) -> Result<Seq<Self, &[u8]>, TryFromSliceError> {
+                Self::[<from_ $e:lower _bytes_slice>](slice)
+            }
+        }
+
+        impl [<From $e BytesHList>] for HNil {}
+
+        impl<H, T, const HN: usize> [<From $e BytesHList>] for HCons<H, HN, T>
+        where
+            Self: [<From $e BytesSlice>],
+        {}
+    )+ }};
+}
+
+endianness_hlist!(Le, Be);
+
 macro_rules! impl_endianness_for_wrappers {
     ($e:ident @ $($ty:ty),+) => {paste!{ $(
         impl<T: From<[<$ty:lower>]>> [<From $e Bytes>]<{ size_of::<[<$ty:lower>]>() }> for $ty<T> {
@@ -480,6 +1170,13 @@ macro_rules! impl_endianness_for_wrappers {
                 Self([<$ty:lower>]::[<from_ $e:lower _bytes>](bytes).into())
             }
         }
+
+        impl<T: From<[<$ty:lower>]>> [<From $e BytesSlice>] for $ty<T> {
+            const WIRE_SIZE: usize = size_of::<[<$ty:lower>]>();
+            fn [<from_ $e:lower _bytes_slice>](slice: &[u8]) -> Result<Seq<Self, &[u8]>, TryFromSliceError> {
+                [<TryFrom $e Bytes>]::[<try_from_ $e:lower _bytes>](slice)
+            }
+        }
     )+ }};
     ($($ty:ty),+) => {
         impl_endianness_for_wrappers!(Le @ $($ty),+);
@@ -489,6 +1186,144 @@ macro_rules! impl_endianness_for_wrappers {
 
 impl_endianness_for_wrappers!(U8, U16, U32, U64, U128, Usize);
 
+macro_rules! endianness_storage {
+    (Common: $e:ident => $ty:ty) => { paste!{
+        #[doc=concat!("[", stringify!($ty), "] stored in ", stringify!($e), " bytes order, converted on access")]
+        ///
+        /// It has alignment 1 and keeps bytes as is on bytes conversion of any endianness.
+        #[derive(Clone, Copy, Default)]
+        #[repr(transparent)]
+        pub struct [<$ty:upper $e>]([u8; size_of::<$ty>()]);
+
+        impl [<$ty:upper $e>] {
+            pub const fn new(value: $ty) -> Self {
+                Self(value.[<to_ $e:lower _bytes>]())
+            }
+            pub const fn get(self) -> $ty {
+                $ty::[<from_ $e:lower _bytes>](self.0)
+            }
+            pub fn set(&mut self, value: $ty) {
+                self.0 = value.[<to_ $e:lower _bytes>]();
+            }
+            pub const fn from_bytes(bytes: [u8; size_of::<$ty>()]) -> Self {
+                Self(bytes)
+            }
+            pub const fn to_bytes(self) -> [u8; size_of::<$ty>()] {
+                self.0
+            }
+        }
+
+        impl core::fmt::Debug for [<$ty:upper $e>] {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_tuple(stringify!([<$ty:upper $e>])).field(&self.get()).finish()
+            }
+        }
+
+        impl From<$ty> for [<$ty:upper $e>] {
+            fn from(value: $ty) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl From<[<$ty:upper $e>]> for $ty {
+            fn from(value: [<$ty:upper $e>]) -> Self {
+                value.get()
+            }
+        }
+
+        impl PartialEq<$ty> for [<$ty:upper $e>] {
+            fn eq(&self, other: &$ty) -> bool {
+                self.get() == *other
+            }
+        }
+
+        impl PartialOrd<$ty> for [<$ty:upper $e>] {
+            fn partial_cmp(&self, other: &$ty) -> Option<core::cmp::Ordering> {
+                self.get().partial_cmp(other)
+            }
+        }
+
+        impl FromLeBytes<{ size_of::<$ty>() }> for [<$ty:upper $e>] {
+            fn from_le_bytes(bytes: [u8; size_of::<$ty>()]) -> Self {
+                Self(bytes)
+            }
+        }
+
+        impl FromBeBytes<{ size_of::<$ty>() }> for [<$ty:upper $e>] {
+            fn from_be_bytes(bytes: [u8; size_of::<$ty>()]) -> Self {
+                Self(bytes)
+            }
+        }
+
+        impl FromLeBytesSlice for [<$ty:upper $e>] {
+            const WIRE_SIZE: usize = size_of::<$ty>();
+            fn from_le_bytes_slice(slice: &[u8]) -> Result<Seq<Self, &[u8]>, TryFromSliceError> {
+                TryFromLeBytes::try_from_le_bytes(slice)
+            }
+        }
+
+        impl FromBeBytesSlice for [<$ty:upper $e>] {
+            const WIRE_SIZE: usize = size_of::<$ty>();
+            fn from_be_bytes_slice(slice: &[u8]) -> Result<Seq<Self, &[u8]>, TryFromSliceError> {
+                TryFromBeBytes::try_from_be_bytes(slice)
+            }
+        }
+    }};
+    // Integers are compared by bytes
+    (Integer: $e:ident => $ty:ty) => { paste!{
+        endianness_storage!(Common: $e => $ty);
+
+        impl PartialEq for [<$ty:upper $e>] {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl Eq for [<$ty:upper $e>] {}
+
+        impl core::hash::Hash for [<$ty:upper $e>] {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.0.hash(state)
+            }
+        }
+
+        impl PartialOrd for [<$ty:upper $e>] {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for [<$ty:upper $e>] {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                self.get().cmp(&other.get())
+            }
+        }
+    }};
+    // Floats are compared by values
+    (Float: $e:ident => $ty:ty) => { paste!{
+        endianness_storage!(Common: $e => $ty);
+
+        impl PartialEq for [<$ty:upper $e>] {
+            fn eq(&self, other: &Self) -> bool {
+                self.get() == other.get()
+            }
+        }
+
+        impl PartialOrd for [<$ty:upper $e>] {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                self.get().partial_cmp(&other.get())
+            }
+        }
+    }};
+    ($kind:ident: $($ty:ty),+ $(,)?) => { $(
+        endianness_storage!($kind: Le => $ty);
+        endianness_storage!($kind: Be => $ty);
+    )+ };
+}
+
+endianness_storage!(Integer: u16, u32, u64, u128, i16, i32, i64, i128);
+endianness_storage!(Float: f32, f64);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -640,6 +1475,247 @@ mod tests {
         assert_eq!(sample, (a, b, c), "mixed integers");
     }
 
+    #[test]
+    fn padded_partition_wire_size() {
+        type P = P3<(u8, u32, u16), 1, 4, 2>;
+        assert_eq!(8, <P as FromLeBytes<7>>::SIZE, "in memory");
+        assert_eq!(7, <P as FromLeBytesSlice>::WIRE_SIZE, "on wire");
+    }
+
+    #[test]
+    fn padded_partition_try_from_slice() {
+        // Slice is split by wire size, not by size of padded partition in memory
+        let Seq {
+            head: P3((a, b, c)),
+            tail,
+        } = P3::<(u8, u32, u16), 1, 4, 2>::from_le_bytes_slice(&DATA[..8]).unwrap();
+        assert_eq!((0x00, 0x44332211, 0x6655), (a, b, c), "little endian");
+        assert_eq!(&[0x77], tail);
+
+        let Seq {
+            head: P3((a, b, c)),
+            tail,
+        } = P3::<(u16, u8, u64), 2, 1, 8>::from_be_bytes_slice(&DATA[..11]).unwrap();
+        assert_eq!((0x0011, 0x22, 0x33445566778899AA), (a, b, c), "big endian");
+        assert!(tail.is_empty());
+
+        let result = P3::<(u8, u32, u16), 1, 4, 2>::from_le_bytes_slice(&DATA[..6]);
+        assert!(result.is_err(), "slice is shorter");
+    }
+
+    #[test]
+    fn array_of_partitions() {
+        let data: [u8; 12] = DATA[..12].try_into().unwrap();
+
+        let result: [P2<(u16, u32), 2, 4>; 2] = data.le_bytes_into();
+        let sample = [P2((0x1100, 0x55443322)), P2((0x7766, 0xBBAA9988))];
+        assert_eq!(sample, result, "little endian");
+
+        let Be(result): Be<[P2<(u16, u32), 2, 4>; 2]> = data.into();
+        let sample = [P2((0x0011, 0x22334455)), P2((0x6677, 0x8899AABB))];
+        assert_eq!(sample, result, "big endian");
+    }
+
+    #[test]
+    fn array_of_wrappers() {
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        struct Id(u16);
+        impl From<u16> for Id {
+            fn from(id: u16) -> Self {
+                Self(id)
+            }
+        }
+
+        let data: [u8; 8] = DATA[..8].try_into().unwrap();
+        let result: [Le<U16<Id>>; 4] = data.le_bytes_into();
+        let sample = [
+            Le(U16(Id(0x1100))),
+            Le(U16(Id(0x3322))),
+            Le(U16(Id(0x5544))),
+            Le(U16(Id(0x7766))),
+        ];
+        assert_eq!(sample, result, "wrapped elements");
+    }
+
+    #[test]
+    fn array_of_arrays() {
+        let data: [u8; 12] = DATA[..12].try_into().unwrap();
+
+        let result: [[u16; 2]; 3] = data.le_bytes_into();
+        let sample = [[0x1100, 0x3322], [0x5544, 0x7766], [0x9988, 0xBBAA]];
+        assert_eq!(sample, result, "integers");
+
+        let result: [[u8; 3]; 4] = data.be_bytes_into();
+        let sample = [[0x00, 0x11, 0x22], [0x33, 0x44, 0x55], [0x66, 0x77, 0x88], [0x99, 0xAA, 0xBB]];
+        assert_eq!(sample, result, "bytes");
+    }
+
+    #[test]
+    fn array_from_slice() {
+        let Seq { head, tail } = <[P2<(u8, u16), 1, 2>; 2]>::from_le_bytes_slice(&DATA[..7]).unwrap();
+        assert_eq!([P2((0x00, 0x2211)), P2((0x33, 0x5544))], head);
+        assert_eq!(&[0x66], tail);
+
+        let result = <[u32; 2]>::from_be_bytes_slice(&DATA[..7]);
+        assert!(result.is_err(), "slice is shorter");
+    }
+
+    /// Collects list of bytes into vector
+    trait Bytes {
+        fn bytes(self) -> std::vec::Vec<u8>;
+    }
+    impl Bytes for HNil {
+        fn bytes(self) -> std::vec::Vec<u8> {
+            vec![]
+        }
+    }
+    impl<T: Bytes> Bytes for HCons<u8, 1, T> {
+        fn bytes(self) -> std::vec::Vec<u8> {
+            let mut result = vec![self.0];
+            result.extend(self.1.bytes());
+            result
+        }
+    }
+
+    #[test]
+    fn const_fn_integers() {
+        const DATA: [u8; 7] = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66];
+        const WORD: Option<Seq<u16, &[u8]>> = le_u16(&DATA);
+        assert_eq!(Some(Seq { head: 0x1100, tail: &DATA[2..] }), WORD);
+        const SIGNED: Option<Seq<i32, &[u8]>> = be_i32(&[0xFF, 0xFF, 0xFF, 0xFE]);
+        assert_eq!(Some(Seq { head: -2, tail: &[][..] }), SIGNED);
+        const BYTE: Option<Seq<i8, &[u8]>> = be_i8(&[0xFE, 0x00]);
+        assert_eq!(Some(Seq { head: -2, tail: &[0x00][..] }), BYTE);
+        assert_eq!(Some(Seq { head: 0x00, tail: &DATA[1..] }), le_u8(&DATA));
+        assert_eq!(None, le_u64(&DATA), "too short");
+    }
+
+    #[test]
+    fn nested_endianness_override() {
+        let data: [u8; 7] = DATA[..7].try_into().unwrap();
+        let sample = (RESULT_LE_U8, 0x11223344, 0x6655u16);
+
+        let Le((a, Be(b), c)): Le<(u8, Be<u32>, u16)> = P3::<_, 1, 4, 2>(data).into();
+        assert_eq!(sample, (a, b, c), "P3 to Le");
+        let Le((a, Be(b), c)): Le<(u8, Be<u32>, u16)> =
+            T3::<[u8; 1], [u8; 4], [u8; 2]>::from(data).into();
+        assert_eq!(sample, (a, b, c), "T3 to Le");
+
+        let Seq { head: Be((Le(a), b)), .. }: Seq<Be<(Le<u16>, u32)>, _> =
+            P2::<_, 2, 4>(&DATA[..]).try_into().unwrap();
+        assert_eq!((RESULT_LE_U16, 0x22334455), (a, b), "P2 slice to Be");
+        let Be((Be(a), b)): Be<(Be<u16>, u8)> =
+            T2::<[u8; 2], [u8; 1]>::from([0x11, 0x22, 0x33]).into();
+        assert_eq!((0x1122, 0x33), (a, b), "redundant wrapper");
+    }
+
+    #[test]
+    fn wrapped_try_from_slice() {
+        let Seq { head: Le(result), tail }: Seq<Le<u32>, _> = DATA[..].try_into().unwrap();
+        assert_eq!(RESULT_LE_U32, result);
+        assert_eq!(&DATA[4..], tail);
+
+        let Seq { head: Be(result), .. }: Seq<Be<u8>, _> = DATA[..].try_into().unwrap();
+        assert_eq!(RESULT_BE_U8, result);
+
+        let Seq { head: Be(result), .. }: Seq<Be<[u16; 2]>, _> = DATA[..].try_into().unwrap();
+        assert_eq!([RESULT_BE_U16, 0x2233], result);
+
+        let Seq { head: (Le(a), Be(b), Le(c)), tail }: Seq<(Le<u8>, Be<u16>, Le<u64>), _> =
+            DATA[..].try_into().unwrap();
+        assert_eq!((0x00, 0x1122, 0xAA99887766554433), (a, b, c));
+        assert_eq!(&DATA[11..], tail);
+
+        let result: Result<Seq<(Le<u16>, Be<u32>), _>, _> = DATA[..5].try_into();
+        assert!(result.is_err(), "too short");
+
+        let Seq { head, tail } = <[Be<u8>; 2]>::from_be_bytes_slice(&DATA[..3]).unwrap();
+        assert_eq!([Be(0x00), Be(0x11)], head, "wrapped bytes from slice trait");
+        assert_eq!(&DATA[2..3], tail);
+    }
+
+    #[test]
+    fn tuple_try_into() {
+        let Seq { head: Le((a, b, c)), tail }: Seq<Le<(u8, u16, u32)>, _> =
+            DATA.as_slice().le_tuple_try_into().unwrap();
+        assert_eq!((RESULT_LE_U8, 0x2211, 0x66554433), (a, b, c), "LE slice");
+        assert_eq!(&DATA[7..], tail);
+
+        let data: [u8; 8] = DATA[..8].try_into().unwrap();
+        let Be((a, b)): Be<([u16; 2], u32)> = data.be_tuple_into();
+        assert_eq!(([RESULT_BE_U16, 0x2233], 0x44556677), (a, b), "BE array");
+
+        let result: Result<Seq<Le<(u32, u32)>, _>, _> = DATA[..7].le_tuple_try_into();
+        assert!(result.is_err(), "too short");
+    }
+
+    #[test]
+    fn storage_types() {
+        use core::mem::{align_of, size_of};
+        use std::format;
+
+        assert_eq!((1, 2), (align_of::<U16Le>(), size_of::<U16Le>()));
+        assert_eq!((1, 16), (align_of::<I128Be>(), size_of::<I128Be>()));
+        assert_eq!((1, 8), (align_of::<F64Le>(), size_of::<F64Le>()));
+
+        let mut value = U32Be::new(RESULT_BE_U32);
+        assert_eq!([0x00, 0x11, 0x22, 0x33], value.to_bytes());
+        value.set(0x44556677);
+        assert_eq!(0x44556677, value.get());
+        assert_eq!("U32Be(1146447479)", format!("{value:?}"));
+
+        let result: U16Le = [0x00, 0x11].be_bytes_into();
+        assert_eq!(RESULT_LE_U16, result.get(), "bytes are kept as is");
+        let result: I16Be = [0xFF, 0xFE].le_bytes_into();
+        assert_eq!(-2, result.get(), "bytes are kept as is");
+
+        assert!(U16Be::new(0x0100) > U16Be::new(0x00FF), "compared by value");
+        assert!(U16Le::new(0x0100) > U16Le::new(0x00FF), "compared by value");
+        assert!(F32Le::new(f32::NAN) != F32Le::new(f32::NAN), "float equality");
+        assert_eq!(F64Be::new(1.5), 1.5);
+
+        let Seq { head, tail } = <[U16Le; 2]>::from_be_bytes_slice(&DATA[..5]).unwrap();
+        assert_eq!([0x1100, 0x3322], head.map(u16::from), "array from slice");
+        assert_eq!(&DATA[4..5], tail);
+    }
+
+    #[test]
+    fn hlist_longer_than_tuple_wrappers() {
+        type B3<T> = HCons<u8, 1, HCons<u8, 1, HCons<u8, 1, T>>>;
+        type B27 = B3<B3<B3<B3<B3<B3<B3<B3<B3<HNil>>>>>>>>>;
+
+        let data: [u8; 27] = DATA[..27].try_into().unwrap();
+        let result: B27 = data.le_bytes_into();
+        assert_eq!(data.as_slice(), result.clone().bytes());
+
+        let (first, .., last) = result.into_tuple();
+        assert_eq!((data[0], data[26]), (first, last), "flat tuple");
+    }
+
+    #[test]
+    fn hlist_mixed_endianness() {
+        let data: [u8; 8] = DATA[..8].try_into().unwrap();
+
+        type L = HCons<u16, 2, HCons<u32, 4, HCons<P2<(u8, u8), 1, 1>, 2, HNil>>>;
+        let (a, b, P2((c, d))) = L::from_le_bytes(data).into_tuple();
+        assert_eq!((0x1100, 0x55443322, 0x66, 0x77), (a, b, c, d), "little endian");
+
+        let Be(HCons(a, HCons(b, HNil))): Be<HCons<u16, 2, HCons<[u16; 3], 6, HNil>>> =
+            data.into();
+        assert_eq!((0x0011, [0x2233, 0x4455, 0x6677]), (a, b), "big endian");
+    }
+
+    #[test]
+    fn hlist_from_slice() {
+        type L = HCons<u8, 1, HCons<u32, 4, HNil>>;
+
+        let Seq { head, tail } = L::from_be_bytes_hlist(&DATA[..6]).unwrap();
+        assert_eq!(HCons(0x00, HCons(0x11223344, HNil)), head);
+        assert_eq!(&[0x55], tail);
+
+        assert!(L::from_be_bytes_hlist(&DATA[..4]).is_err(), "slice is shorter");
+    }
+
     macro_rules! integers {
         ($e:ident => $($ty:ty),+ $(,)?) => { paste!{ $(
 
@@ -0,0 +1,728 @@
+/*!
+Runtime layout descriptors for schema driven parsing
+
+Compile time partitions (`P3::<_, 1, 2, 4>`) require widths known at compile time.
+[Layout] describes the same fields at runtime, e.g. loaded from configuration, and decodes
+them into `u128` / `i128` values.
+
+Bytes fields are decoded from slices with the same byte order as [FromLeBytes] /
+[FromBeBytes]. Bits fields are decoded from any [BitSource] with [BitSource::lsb_bits] /
+[BitSource::msb_bits], the same methods [FromLsb] / [FromMsb] partitions use, so results are
+identical to static API. Bits fields of slices are decoded as bits of bytes array.
+
+[FromLeBytes]: crate::endianness::FromLeBytes
+[FromBeBytes]: crate::endianness::FromBeBytes
+[FromLsb]: crate::bit_numbering::FromLsb
+[FromMsb]: crate::bit_numbering::FromMsb
+
+## Examples
+Bytes layout
+```rust
+# use heterob::layout::*;
+let data = [0x00, 0x11,0x22, 0xFF,0xFF,0xFF,0xFE, 0x77];
+let layout = Layout([
+    Field::bytes(1),
+    Field::bytes(2),
+    Field::bytes(4).be().signed(),
+]);
+
+let mut values = [0i128; 3];
+let tail = layout.decode_slice_into(&data, &mut values).unwrap();
+assert_eq!([0x00, 0x2211, -2], values);
+assert_eq!(&[0x77], tail);
+```
+
+Bits layout
+```rust
+# use heterob::{P3, bit_numbering::*, layout::*};
+let data: u16 = 0b1111_0000_1100_1010;
+let layout = Layout([Field::bits(7), Field::bits(1), Field::bits(8)]);
+
+let mut values = [0u128; 3];
+layout.decode_lsb_into(&data, &mut values).unwrap();
+
+let (a, b, c): (u128, u128, u128) = P3::<_, 7, 1, 8>(data).lsb_into();
+assert_eq!([a, b, c], values);
+```
+
+Bits and bytes fields mixed in slice. Bits of little endian fields are numbered LSB 0, bits of
+big endian fields are numbered MSB 0. Bytes fields should start at byte boundary.
+```rust
+# use heterob::layout::*;
+let data = [0b1010_0101, 0x11, 0x22, 0x33];
+let layout = Layout([Field::bits(4).be(), Field::bits(4).be(), Field::bytes(2)]);
+
+let mut values = [0u128; 3];
+let tail = layout.decode_slice_into(&data, &mut values).unwrap();
+assert_eq!([0b1010, 0b0101, 0x2211], values);
+assert_eq!(&[0x33], tail);
+```
+
+Annotated dumps of partitions for debugging, written through [core::fmt::Write]
+```rust
+# use heterob::{P3, P4};
+let mut dump = String::new();
+P4::<_, 1, 2, 4, 1>([0x00, 0x11,0x22, 0x33,0x44,0x55,0x66, 0x77]).write_le_dump(&mut dump).unwrap();
+P3::<_, 7, 1, 8>(0b1111_0000_1100_1010u16).write_lsb_dump(&mut dump).unwrap();
+
+let sample = "\
+0000: 00|11 22|33 44 55 66|77
+[0] 0+1: 00 = 0x0
+[1] 1+2: 11 22 = 0x2211
+[2] 3+4: 33 44 55 66 = 0x66554433
+[3] 7+1: 77 = 0x77
+1111000011001010
+[0] 0+7: mask 0x007f = 0x4a
+[1] 7+1: mask 0x0080 = 0x1
+[2] 8+8: mask 0xff00 = 0xf0
+";
+assert_eq!(sample, dump);
+```
+*/
+
+use core::fmt::{self, Write};
+
+use crate::bit_numbering::{field_mask, BitSource};
+use crate::endianness::{FromBeBytes, FromLeBytes};
+
+#[cfg(feature = "alloc")]
+use {crate::Seq, alloc::vec::Vec};
+
+/// Field width
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Width {
+    /// Width in bits
+    Bits(usize),
+    /// Width in bytes
+    Bytes(usize),
+}
+
+impl Width {
+    /// Width in bits, `None` if it overflows [usize]
+    pub const fn bits(&self) -> Option<usize> {
+        match *self {
+            Self::Bits(n) => Some(n),
+            Self::Bytes(n) => n.checked_mul(8),
+        }
+    }
+}
+
+/// Bytes order of field
+///
+/// Bits fields decoded from slices are numbered LSB 0 for little endian and MSB 0 for big endian.
+/// Bits fields decoded from [BitSource] ignore it, bit numbering is defined by decoding method.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Endian {
+    #[default]
+    Le,
+    Be,
+}
+
+/// Runtime field descriptor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Field {
+    pub width: Width,
+    pub endian: Endian,
+    /// Value is sign extended from field width
+    pub signed: bool,
+}
+
+impl Field {
+    /// Unsigned little endian field of `n` bytes
+    pub const fn bytes(n: usize) -> Self {
+        Self {
+            width: Width::Bytes(n),
+            endian: Endian::Le,
+            signed: false,
+        }
+    }
+    /// Unsigned field of `n` bits
+    pub const fn bits(n: usize) -> Self {
+        Self {
+            width: Width::Bits(n),
+            endian: Endian::Le,
+            signed: false,
+        }
+    }
+    /// Same field in big endian bytes order
+    pub const fn be(self) -> Self {
+        Self {
+            endian: Endian::Be,
+            ..self
+        }
+    }
+    /// Same field sign extended
+    pub const fn signed(self) -> Self {
+        Self {
+            signed: true,
+            ..self
+        }
+    }
+    /// Raw value of bytes field, `bytes` are at most 16 bytes long
+    fn bytes_value(&self, bytes: &[u8]) -> u128 {
+        let n = bytes.len();
+        // Zero padding of most significant bytes keeps value intact
+        let mut padded = [0u8; 16];
+        match self.endian {
+            Endian::Le => {
+                padded[..n].copy_from_slice(bytes);
+                <u128 as FromLeBytes<16>>::from_le_bytes(padded)
+            }
+            Endian::Be => {
+                padded[16 - n..].copy_from_slice(bytes);
+                <u128 as FromBeBytes<16>>::from_be_bytes(padded)
+            }
+        }
+    }
+    /// Raw value of bits field of at most 128 bits starting from bit `offset` of `slice`
+    fn slice_bits(&self, slice: &[u8], offset: usize, n: usize) -> u128 {
+        // Field with offset within the first byte spans at most 17 bytes
+        let mut bytes = [0u8; 17];
+        let span = &slice[offset / 8..(offset + n).div_ceil(8)];
+        bytes[..span.len()].copy_from_slice(span);
+        match self.endian {
+            Endian::Le => bytes.lsb_bits(offset % 8, n),
+            Endian::Be => bytes.msb_bits(offset % 8, n),
+        }
+    }
+    /// Signed values are written as decimals, unsigned ones as hexadecimals
+    fn write_value<W: Write>(&self, w: &mut W, raw: u128) -> fmt::Result {
+        let value = self.value(raw);
+        if self.signed {
+            write!(w, " {}", value as i128)
+        } else {
+            write!(w, " 0x{value:x}")
+        }
+    }
+    /// Sign extended (if signed) raw field value
+    fn value(&self, raw: u128) -> u128 {
+        let shift = self.width.bits().map_or(0, |n| 128usize.saturating_sub(n));
+        if self.signed && shift > 0 && shift < 128 {
+            (((raw << shift) as i128) >> shift) as u128
+        } else {
+            raw
+        }
+    }
+}
+
+/// Decoded field value
+pub trait FieldValue {
+    /// Value from sign extended field bits
+    fn from_field(value: u128) -> Self;
+}
+
+impl FieldValue for u128 {
+    fn from_field(value: u128) -> Self {
+        value
+    }
+}
+
+impl FieldValue for i128 {
+    fn from_field(value: u128) -> Self {
+        value as i128
+    }
+}
+
+/// Layout decoding error
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutError {
+    /// Field at index is wider than 128 bits
+    TooWide(usize),
+    /// Not enough data for field at index
+    OutOfBounds(usize),
+    /// Bytes field at index doesn't start at byte boundary
+    Unaligned(usize),
+    /// Output buffer is shorter than number of fields
+    Output { fields: usize, values: usize },
+}
+
+impl LayoutError {
+    /// Index of failed field
+    pub const fn index(&self) -> Option<usize> {
+        match *self {
+            Self::TooWide(n) | Self::OutOfBounds(n) | Self::Unaligned(n) => Some(n),
+            Self::Output { .. } => None,
+        }
+    }
+}
+
+/// Runtime list of fields
+///
+/// Fields could be stored in array, slice or vector.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layout<F>(pub F);
+
+impl<F: AsRef<[Field]>> Layout<F> {
+    /// Fields of layout
+    pub fn fields(&self) -> &[Field] {
+        self.0.as_ref()
+    }
+
+    /// Total width in bits, `None` if it overflows [usize]
+    pub fn bits(&self) -> Option<usize> {
+        self.fields()
+            .iter()
+            .try_fold(0usize, |sum, f| sum.checked_add(f.width.bits()?))
+    }
+
+    fn check_output<V>(&self, values: &[V]) -> Result<(), LayoutError> {
+        let fields = self.fields().len();
+        if values.len() < fields {
+            return Err(LayoutError::Output {
+                fields,
+                values: values.len(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Decodes fields from slice into `values`, returns slice tail
+    ///
+    /// Tail starts from the byte following the last decoded bit.
+    pub fn decode_slice_into<'a, V: FieldValue>(
+        &self,
+        slice: &'a [u8],
+        values: &mut [V],
+    ) -> Result<&'a [u8], LayoutError> {
+        self.check_output(values)?;
+        // Offset in bits
+        let mut offset = 0;
+        for (index, (field, value)) in self.fields().iter().zip(values).enumerate() {
+            let (raw, n) = match field.width {
+                Width::Bytes(n) => {
+                    if offset % 8 != 0 {
+                        return Err(LayoutError::Unaligned(index));
+                    }
+                    if n > 16 {
+                        return Err(LayoutError::TooWide(index));
+                    }
+                    let Some(bytes) = slice.get(offset / 8..offset / 8 + n) else {
+                        return Err(LayoutError::OutOfBounds(index));
+                    };
+                    (field.bytes_value(bytes), n * 8)
+                }
+                Width::Bits(n) => {
+                    if n > 128 {
+                        return Err(LayoutError::TooWide(index));
+                    }
+                    if offset + n > slice.len() * 8 {
+                        return Err(LayoutError::OutOfBounds(index));
+                    }
+                    (field.slice_bits(slice, offset, n), n)
+                }
+            };
+            *value = V::from_field(field.value(raw));
+            offset += n;
+        }
+        Ok(&slice[offset.div_ceil(8)..])
+    }
+
+    /// Decodes fields from `data` according to LSB 0 bit numbering into `values`
+    pub fn decode_lsb_into<T: BitSource, V: FieldValue>(
+        &self,
+        data: &T,
+        values: &mut [V],
+    ) -> Result<(), LayoutError> {
+        self.decode_bits_into(values, |index, n| data.lsb_bits(index, n), T::BITS)
+    }
+
+    /// Decodes fields from `data` according to MSB 0 bit numbering into `values`
+    pub fn decode_msb_into<T: BitSource, V: FieldValue>(
+        &self,
+        data: &T,
+        values: &mut [V],
+    ) -> Result<(), LayoutError> {
+        self.decode_bits_into(values, |index, n| data.msb_bits(index, n), T::BITS)
+    }
+
+    fn decode_bits_into<V: FieldValue>(
+        &self,
+        values: &mut [V],
+        bits: impl Fn(usize, usize) -> u128,
+        total: usize,
+    ) -> Result<(), LayoutError> {
+        self.check_output(values)?;
+        let mut offset = 0;
+        for (index, (field, value)) in self.fields().iter().zip(values).enumerate() {
+            let n = match field.width.bits() {
+                Some(n) if n <= 128 => n,
+                _ => return Err(LayoutError::TooWide(index)),
+            };
+            if offset + n > total {
+                return Err(LayoutError::OutOfBounds(index));
+            }
+            *value = V::from_field(field.value(bits(offset, n)));
+            offset += n;
+        }
+        Ok(())
+    }
+
+    /// Writes hexdump of `slice` with field boundaries followed by decoded fields
+    ///
+    /// Byte field boundaries are marked with `|`. Every field line contains field index, decimal
+    /// offset and width, bytes and value. Offset and width of bits fields are in bits. Values of
+    /// fields wider than 16 bytes are omitted. Widths that overflow [usize] in bits saturate.
+    pub fn write_bytes_dump<W: Write>(&self, w: &mut W, slice: &[u8]) -> fmt::Result {
+        // Fields end offsets in bits are increasing, so they are consumed along with bytes
+        let mut ends = self
+            .fields()
+            .iter()
+            .scan(0usize, |offset, field| {
+                *offset = offset.saturating_add(field.width.bits().unwrap_or(usize::MAX));
+                Some(*offset)
+            })
+            .peekable();
+        for (row, chunk) in slice.chunks(16).enumerate() {
+            write!(w, "{:04x}:", row * 16)?;
+            for (n, byte) in chunk.iter().enumerate() {
+                let pos = (row * 16 + n) * 8;
+                while ends.next_if(|&end| end < pos).is_some() {}
+                let sep = if pos > 0 && ends.next_if_eq(&pos).is_some() { '|' } else { ' ' };
+                write!(w, "{sep}{byte:02x}")?;
+            }
+            writeln!(w)?;
+        }
+        // Offset in bits
+        let mut offset = 0usize;
+        for (index, field) in self.fields().iter().enumerate() {
+            match field.width {
+                Width::Bytes(n) => {
+                    write!(w, "[{index}] {}+{n}:", offset / 8)?;
+                    let end = (offset / 8).saturating_add(n);
+                    match slice.get(offset / 8..end) {
+                        _ if !offset.is_multiple_of(8) => w.write_str(" unaligned")?,
+                        None => w.write_str(" out of bounds")?,
+                        Some(bytes) => {
+                            for byte in bytes {
+                                write!(w, " {byte:02x}")?;
+                            }
+                            if n <= 16 {
+                                w.write_str(" =")?;
+                                field.write_value(w, field.bytes_value(bytes))?;
+                            }
+                        }
+                    }
+                }
+                Width::Bits(n) => {
+                    write!(w, "[{index}] {offset}+{n} bits:")?;
+                    if n > 128 || offset.saturating_add(n) > slice.len() * 8 {
+                        w.write_str(" out of bounds")?;
+                    } else {
+                        field.write_value(w, field.slice_bits(slice, offset, n))?;
+                    }
+                }
+            }
+            writeln!(w)?;
+            offset = offset.saturating_add(field.width.bits().unwrap_or(usize::MAX));
+        }
+        Ok(())
+    }
+
+    /// Writes bits of `data` followed by fields masks and values according to LSB 0 bit
+    /// numbering
+    pub fn write_lsb_dump<W: Write, T: BitSource>(&self, w: &mut W, data: &T) -> fmt::Result {
+        self.write_bits_dump(w, |index, n| data.lsb_bits(index, n), T::BITS, |index, _| index)
+    }
+
+    /// Writes bits of `data` followed by fields masks and values according to MSB 0 bit
+    /// numbering
+    pub fn write_msb_dump<W: Write, T: BitSource>(&self, w: &mut W, data: &T) -> fmt::Result {
+        let index = |index: usize, n: usize| T::BITS.saturating_sub(index + n);
+        self.write_bits_dump(w, |index, n| data.msb_bits(index, n), T::BITS, index)
+    }
+
+    /// Masks are written only for sources that fit into [u128]
+    fn write_bits_dump<W: Write>(
+        &self,
+        w: &mut W,
+        bits: impl Fn(usize, usize) -> u128,
+        total: usize,
+        mask_index: impl Fn(usize, usize) -> usize,
+    ) -> fmt::Result {
+        let has_masks = total <= 128;
+        let digits = total.div_ceil(4);
+        if has_masks {
+            writeln!(w, "{:0total$b}", bits(0, total))?;
+        }
+        let mut offset = 0usize;
+        for (index, field) in self.fields().iter().enumerate() {
+            // Widths that overflow usize in bits saturate
+            let n = field.width.bits().unwrap_or(usize::MAX);
+            write!(w, "[{index}] {offset}+{n}:")?;
+            if n > 128 || offset.saturating_add(n) > total {
+                w.write_str(" out of bounds")?;
+            } else {
+                if has_masks {
+                    let mask = field_mask(mask_index(offset, n), n);
+                    write!(w, " mask 0x{mask:0digits$x} =")?;
+                }
+                field.write_value(w, bits(offset, n))?;
+            }
+            writeln!(w)?;
+            offset = offset.saturating_add(n);
+        }
+        Ok(())
+    }
+
+    /// Decodes fields from slice into vector
+    #[cfg(feature = "alloc")]
+    pub fn decode_slice<'a, V: FieldValue + Default + Clone>(
+        &self,
+        slice: &'a [u8],
+    ) -> Result<Seq<Vec<V>, &'a [u8]>, LayoutError> {
+        let mut head = alloc::vec![V::default(); self.fields().len()];
+        let tail = self.decode_slice_into(slice, &mut head)?;
+        Ok(Seq { head, tail })
+    }
+
+    /// Decodes fields from `data` according to LSB 0 bit numbering into vector
+    #[cfg(feature = "alloc")]
+    pub fn decode_lsb<T: BitSource, V: FieldValue + Default + Clone>(
+        &self,
+        data: &T,
+    ) -> Result<Vec<V>, LayoutError> {
+        let mut values = alloc::vec![V::default(); self.fields().len()];
+        self.decode_lsb_into(data, &mut values)?;
+        Ok(values)
+    }
+
+    /// Decodes fields from `data` according to MSB 0 bit numbering into vector
+    #[cfg(feature = "alloc")]
+    pub fn decode_msb<T: BitSource, V: FieldValue + Default + Clone>(
+        &self,
+        data: &T,
+    ) -> Result<Vec<V>, LayoutError> {
+        let mut values = alloc::vec![V::default(); self.fields().len()];
+        self.decode_msb_into(data, &mut values)?;
+        Ok(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bit_numbering::{LsbInto, MsbInto};
+    use crate::endianness::{BeBytesInto, LeBytesInto};
+    use crate::{P3, P4};
+
+    const DATA: [u8; 8] = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0xF7];
+
+    #[test]
+    fn slice_same_as_static() {
+        let P4((a, b, c, d)): P4<(u8, u16, u32, u8), 1, 2, 4, 1> = DATA.le_bytes_into();
+        let layout = Layout([Field::bytes(1), Field::bytes(2), Field::bytes(4), Field::bytes(1)]);
+        let mut values = [0u128; 4];
+        let tail = layout.decode_slice_into(&DATA, &mut values).unwrap();
+        assert_eq!([a as u128, b as u128, c as u128, d as u128], values, "LE");
+        assert!(tail.is_empty());
+
+        let P3((a, _, c)): P3<(u16, [u8; 1], u32), 2, 1, 4> =
+            <[u8; 7]>::try_from(&DATA[1..]).unwrap().be_bytes_into();
+        let layout = Layout([Field::bytes(2).be(), Field::bytes(1), Field::bytes(4).be().signed()]);
+        let mut values = [0i128; 3];
+        let tail = layout.decode_slice_into(&DATA[1..], &mut values).unwrap();
+        assert_eq!([a as i128, 0x33, c as i32 as i128], values, "BE");
+        assert!(tail.is_empty());
+    }
+
+    #[test]
+    fn bits_same_as_static() {
+        let data = u64::from_le_bytes(DATA);
+        let (a, b, c): (u8, u32, u32) = P3::<_, 5, 27, 32>(data).lsb_into();
+        let layout = Layout([Field::bits(5), Field::bits(27), Field::bits(32)]);
+        let mut values = [0u128; 3];
+        layout.decode_lsb_into(&data, &mut values).unwrap();
+        assert_eq!([a as u128, b as u128, c as u128], values, "LSB");
+
+        let (a, b, c): (u8, u32, u32) = P3::<_, 5, 27, 32>(DATA).msb_into();
+        layout.decode_msb_into(&DATA, &mut values).unwrap();
+        assert_eq!([a as u128, b as u128, c as u128], values, "MSB array");
+    }
+
+    #[test]
+    fn slice_bits_same_as_static() {
+        let (a, b, c): (u8, u32, u16) = P3::<_, 5, 27, 12>(DATA).lsb_into();
+        let layout = Layout([Field::bits(5), Field::bits(27), Field::bits(12)]);
+        let mut values = [0u128; 3];
+        let tail = layout.decode_slice_into(&DATA, &mut values).unwrap();
+        assert_eq!([a as u128, b as u128, c as u128], values, "LSB");
+        assert_eq!(&DATA[6..], tail, "partially decoded byte is skipped");
+
+        let (a, b, c): (u8, u32, u16) = P3::<_, 5, 27, 12>(DATA).msb_into();
+        let layout = Layout([Field::bits(5).be(), Field::bits(27).be(), Field::bits(12).be()]);
+        layout.decode_slice_into(&DATA, &mut values).unwrap();
+        assert_eq!([a as u128, b as u128, c as u128], values, "MSB");
+
+        let (a, b, c): (u8, u16, u32) = P3::<_, 3, 5, 16>(DATA).lsb_into();
+        let layout = Layout([Field::bits(3), Field::bits(5), Field::bytes(2), Field::bits(128)]);
+        let mut values = [0u128; 4];
+        assert_eq!(
+            Err(LayoutError::OutOfBounds(3)),
+            layout.decode_slice_into(&DATA, &mut values)
+        );
+        assert_eq!([a as u128, b as u128, c as u128], values[..3], "mixed with bytes");
+    }
+
+    #[test]
+    fn signed_fields() {
+        let layout = Layout([Field::bits(4).signed(), Field::bits(4), Field::bits(8).signed()]);
+        let mut values = [0i128; 3];
+        layout.decode_lsb_into(&0xF7_8Fu16, &mut values).unwrap();
+        assert_eq!([-1, 8, -9], values);
+
+        let layout = Layout([Field::bytes(16).signed()]);
+        layout.decode_slice_into(&[0xFF; 16], &mut values).unwrap();
+        assert_eq!(-1, values[0], "full width");
+    }
+
+    #[test]
+    fn errors() {
+        let mut values = [0u128; 2];
+        let layout = Layout([Field::bytes(4), Field::bytes(5)]);
+        assert_eq!(
+            Err(LayoutError::OutOfBounds(1)),
+            layout.decode_slice_into(&DATA, &mut values)
+        );
+        let layout = Layout([Field::bits(4), Field::bytes(17)]);
+        assert_eq!(Err(LayoutError::Unaligned(1)), layout.decode_slice_into(&DATA, &mut values));
+        assert_eq!(Err(LayoutError::TooWide(1)), layout.decode_lsb_into(&[0u64; 4], &mut values));
+        let layout = Layout([Field::bits(4), Field::bits(5)]);
+        assert_eq!(Err(LayoutError::OutOfBounds(1)), layout.decode_msb_into(&0u8, &mut values));
+        let result = Layout(&[Field::bits(1); 3][..]).decode_lsb_into(&0u8, &mut values);
+        assert_eq!(Some(LayoutError::Output { fields: 3, values: 2 }), result.err());
+    }
+
+    #[test]
+    fn width_overflow() {
+        use std::{format, string::String};
+
+        let mut values = [0u128; 3];
+        let layout = Layout([Field::bytes(1), Field::bytes(usize::MAX), Field::bits(4)]);
+        assert_eq!(None, layout.bits());
+        assert_eq!(Some(16), Layout([Field::bytes(1), Field::bits(8)]).bits());
+        assert_eq!(Err(LayoutError::TooWide(1)), layout.decode_slice_into(&DATA, &mut values));
+        assert_eq!(Err(LayoutError::TooWide(1)), layout.decode_lsb_into(&0u64, &mut values));
+        assert_eq!(Err(LayoutError::TooWide(1)), layout.decode_msb_into(&0u64, &mut values));
+
+        let mut dump = String::new();
+        layout.write_bytes_dump(&mut dump, &DATA[..2]).unwrap();
+        let max = usize::MAX;
+        let sample = format!(
+            "\
+0000: 00|11
+[0] 0+1: 00 = 0x0
+[1] 1+{max}: out of bounds
+[2] {max}+4 bits: out of bounds
+"
+        );
+        assert_eq!(sample, dump, "bytes");
+
+        let mut dump = String::new();
+        layout.write_lsb_dump(&mut dump, &0u16).unwrap();
+        layout.write_msb_dump(&mut dump, &0u16).unwrap();
+        let sample = format!(
+            "\
+0000000000000000
+[0] 0+8: mask 0x00ff = 0x0
+[1] 8+{max}: out of bounds
+[2] {max}+4: out of bounds
+0000000000000000
+[0] 0+8: mask 0xff00 = 0x0
+[1] 8+{max}: out of bounds
+[2] {max}+4: out of bounds
+"
+        );
+        assert_eq!(sample, dump, "bits");
+    }
+
+    #[test]
+    fn bytes_dump() {
+        use std::string::String;
+
+        let mut dump = String::new();
+        let data = [0xFF; 20];
+        let layout = Layout([Field::bytes(2).be().signed(), Field::bytes(17), Field::bits(3)]);
+        layout.write_bytes_dump(&mut dump, &data).unwrap();
+        let sample = "\
+0000: ff ff|ff ff ff ff ff ff ff ff ff ff ff ff ff ff
+0010: ff ff ff|ff
+[0] 0+2: ff ff = -1
+[1] 2+17: ff ff ff ff ff ff ff ff ff ff ff ff ff ff ff ff ff
+[2] 152+3 bits: 0x7
+";
+        assert_eq!(sample, dump, "signed, wide and bits fields");
+
+        let mut dump = String::new();
+        let layout = Layout([Field::bits(4).be(), Field::bits(12).be(), Field::bits(4), Field::bytes(1)]);
+        layout.write_bytes_dump(&mut dump, &DATA[..4]).unwrap();
+        let sample = "\
+0000: 00 11|22 33
+[0] 0+4 bits: 0x0
+[1] 4+12 bits: 0x11
+[2] 16+4 bits: 0x2
+[3] 2+1: unaligned
+";
+        assert_eq!(sample, dump, "bits fields");
+
+        let mut dump = String::new();
+        P3::<_, 1, 2, 4>(&DATA[5..]).write_be_dump(&mut dump).unwrap();
+        let sample = "\
+0000: 55|66 f7
+[0] 0+1: 55 = 0x55
+[1] 1+2: 66 f7 = 0x66f7
+[2] 3+4: out of bounds
+";
+        assert_eq!(sample, dump, "short slice");
+    }
+
+    #[test]
+    fn bits_dump() {
+        use std::string::String;
+
+        let mut dump = String::new();
+        P3::<_, 7, 1, 8>(0b1111_0000_1100_1010u16).write_msb_dump(&mut dump).unwrap();
+        let sample = "\
+1111000011001010
+[0] 0+7: mask 0xfe00 = 0x78
+[1] 7+1: mask 0x0100 = 0x0
+[2] 8+8: mask 0x00ff = 0xca
+";
+        assert_eq!(sample, dump, "MSB");
+        type P = P3<u16, 7, 1, 8>;
+        assert_eq!(
+            (P::MASKS_MSB, P::MASKS_LSB),
+            ([0xfe00, 0x0100, 0x00ff], [0x007f, 0x0080, 0xff00]),
+            "same masks as partition consts"
+        );
+
+        let mut dump = String::new();
+        P3::<_, 4, 120, 8>([0u8; 17]).write_lsb_dump(&mut dump).unwrap();
+        let sample = "\
+[0] 0+4: 0x0
+[1] 4+120: 0x0
+[2] 124+8: 0x0
+";
+        assert_eq!(sample, dump, "no masks for sources wider than 128 bits");
+
+        let mut dump = String::new();
+        let layout = Layout([Field::bits(4).signed(), Field::bits(8)]);
+        layout.write_lsb_dump(&mut dump, &0xFFu8).unwrap();
+        let sample = "\
+11111111
+[0] 0+4: mask 0x0f = -1
+[1] 4+8: out of bounds
+";
+        assert_eq!(sample, dump, "signed and out of bounds");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn vector_output() {
+        let layout = Layout(alloc::vec![Field::bytes(2), Field::bytes(2).be()]);
+        let Seq { head, tail } = layout.decode_slice::<u128>(&DATA).unwrap();
+        assert_eq!(alloc::vec![0x1100, 0x2233], head);
+        assert_eq!(&DATA[4..], tail);
+        let values: Vec<u128> = layout.decode_msb(&0x1234_5678u32).unwrap();
+        assert_eq!(alloc::vec![0x1234, 0x5678], values);
+    }
+}
@@ -5,8 +5,16 @@
 #[macro_use]
 extern crate std;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 pub mod common;
 pub use common::*;
 
 pub mod bit_numbering;
 pub mod endianness;
+
+pub mod layout;
+
+#[cfg(feature = "derive")]
+pub use heterob_derive::{FromBeBytes, FromLeBytes, FromLsb, FromMsb};